@@ -76,6 +76,26 @@ impl From<(&Statement, &Span)> for AstNode {
         range,
         children,
       },
+      Statement::CompoundAssignment(lhs, _, rhs) => {
+        children.push(Self::from((&lhs.0, &lhs.1)));
+        children.push(Self::from((&rhs.0, &rhs.1)));
+
+        Self {
+          kind: statement.kind(),
+          range,
+          children,
+        }
+      }
+      Statement::Const(target, expression) => {
+        children.push(Self::from((&target.0, &target.1)));
+        children.push(Self::from((&expression.0, &expression.1)));
+
+        Self {
+          kind: statement.kind(),
+          range,
+          children,
+        }
+      }
       Statement::Continue => Self {
         kind: statement.kind(),
         range,
@@ -90,7 +110,8 @@ impl From<(&Statement, &Span)> for AstNode {
           children,
         }
       }
-      Statement::For(_, iterable, body) => {
+      Statement::For(target, iterable, body) => {
+        children.push(Self::from((&target.0, &target.1)));
         children.push(Self::from((&iterable.0, &iterable.1)));
 
         for (statement, span) in body {
@@ -103,7 +124,13 @@ impl From<(&Statement, &Span)> for AstNode {
           children,
         }
       }
-      Statement::Function(_, _, body) => {
+      Statement::Function(_, parameters, body) => {
+        for parameter in parameters {
+          if let Parameter::Default(_, (default, span)) = parameter {
+            children.push(Self::from((default, span)));
+          }
+        }
+
         for (statement, span) in body {
           children.push(Self::from((statement, span)));
         }
@@ -114,11 +141,13 @@ impl From<(&Statement, &Span)> for AstNode {
           children,
         }
       }
-      Statement::If(condition, then_branch, else_branch) => {
-        children.push(Self::from((&condition.0, &condition.1)));
+      Statement::If(branches, else_branch) => {
+        for (condition, body) in branches {
+          children.push(Self::from((&condition.0, &condition.1)));
 
-        for (statement, span) in then_branch {
-          children.push(Self::from((statement, span)));
+          for (statement, span) in body {
+            children.push(Self::from((statement, span)));
+          }
         }
 
         if let Some(else_statements) = else_branch {
@@ -133,6 +162,24 @@ impl From<(&Statement, &Span)> for AstNode {
           children,
         }
       }
+      Statement::Import(_, _) => Self {
+        kind: statement.kind(),
+        range,
+        children,
+      },
+      Statement::Let(target, expression) => {
+        children.push(Self::from((&target.0, &target.1)));
+
+        if let Some(expression) = expression {
+          children.push(Self::from((&expression.0, &expression.1)));
+        }
+
+        Self {
+          kind: statement.kind(),
+          range,
+          children,
+        }
+      }
       Statement::Loop(body) => {
         for (statement, span) in body {
           children.push(Self::from((statement, span)));
@@ -155,6 +202,30 @@ impl From<(&Statement, &Span)> for AstNode {
           children,
         }
       }
+      Statement::Throw(expression) => {
+        children.push(Self::from((&expression.0, &expression.1)));
+
+        Self {
+          kind: statement.kind(),
+          range,
+          children,
+        }
+      }
+      Statement::Try(body, catch, finally) => {
+        let handler = catch.iter().flat_map(|(_, handler)| handler);
+
+        for (statement, span) in
+          body.iter().chain(handler).chain(finally.iter().flatten())
+        {
+          children.push(Self::from((statement, span)));
+        }
+
+        Self {
+          kind: statement.kind(),
+          range,
+          children,
+        }
+      }
       Statement::While(condition, body) => {
         children.push(Self::from((&condition.0, &condition.1)));
 
@@ -186,10 +257,34 @@ impl From<(&AssignmentTarget, &Span)> for AstNode {
         range,
         children,
       },
+      AssignmentTarget::List(targets) => {
+        for (target, span) in targets {
+          children.push(Self::from((target, span)));
+        }
+
+        Self {
+          kind: target.kind(),
+          range,
+          children,
+        }
+      }
       AssignmentTarget::ListAccess(list, index) => {
         children.push(Self::from((&list.0, &list.1)));
         children.push(Self::from((&index.0, &index.1)));
 
+        Self {
+          kind: target.kind(),
+          range,
+          children,
+        }
+      }
+      AssignmentTarget::Slice(list, slice) => {
+        children.push(Self::from((&list.0, &list.1)));
+
+        for (bound, span) in slice.bounds().into_iter().flatten() {
+          children.push(Self::from((bound, span)));
+        }
+
         Self {
           kind: target.kind(),
           range,
@@ -224,7 +319,37 @@ impl From<(&Expression, &Span)> for AstNode {
         range,
         children,
       },
-      Expression::Function(_, body) => {
+      Expression::Comparison(first, rest) => {
+        children.push(Self::from((&first.0, &first.1)));
+
+        for (_, (operand, span)) in rest {
+          children.push(Self::from((operand, span)));
+        }
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
+      Expression::Conditional(condition, then_branch, else_branch) => {
+        children.push(Self::from((&condition.0, &condition.1)));
+        children.push(Self::from((&then_branch.0, &then_branch.1)));
+        children.push(Self::from((&else_branch.0, &else_branch.1)));
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
+      Expression::Function(parameters, body) => {
+        for parameter in parameters {
+          if let Parameter::Default(_, (default, span)) = parameter {
+            children.push(Self::from((default, span)));
+          }
+        }
+
         for (statement, span) in body {
           children.push(Self::from((statement, span)));
         }
@@ -235,13 +360,17 @@ impl From<(&Expression, &Span)> for AstNode {
           children,
         }
       }
-      Expression::FunctionCall(function, arguments) => {
+      Expression::FunctionCall(function, arguments, named) => {
         children.push(Self::from((&function.0, &function.1)));
 
         for (ast, span) in arguments {
           children.push(Self::from((ast, span)));
         }
 
+        for (_, (ast, span)) in named {
+          children.push(Self::from((ast, span)));
+        }
+
         Self {
           kind: expression.kind(),
           range,
@@ -253,6 +382,17 @@ impl From<(&Expression, &Span)> for AstNode {
         range,
         children,
       },
+      Expression::Interpolation(parts) => {
+        for (part, span) in parts {
+          children.push(Self::from((part, span)));
+        }
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
       Expression::List(items) => {
         for (item, span) in items {
           children.push(Self::from((item, span)));
@@ -274,6 +414,46 @@ impl From<(&Expression, &Span)> for AstNode {
           children,
         }
       }
+      Expression::Map(entries) => {
+        for (key, value) in entries {
+          children.push(Self::from((&key.0, &key.1)));
+          children.push(Self::from((&value.0, &value.1)));
+        }
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
+      Expression::Match(subject, arms) => {
+        children.push(Self::from((&subject.0, &subject.1)));
+
+        for arm in arms {
+          children.push(Self::from((&arm.pattern.0, &arm.pattern.1)));
+
+          if let Some((guard, span)) = &arm.guard {
+            children.push(Self::from((guard, span)));
+          }
+
+          children.push(Self::from((&arm.body.0, &arm.body.1)));
+        }
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
+      Expression::Member(base, _) => {
+        children.push(Self::from((&base.0, &base.1)));
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
       Expression::Null => Self {
         kind: expression.kind(),
         range,
@@ -284,6 +464,19 @@ impl From<(&Expression, &Span)> for AstNode {
         range,
         children,
       },
+      Expression::Slice(list, slice) => {
+        children.push(Self::from((&list.0, &list.1)));
+
+        for (bound, span) in slice.bounds().into_iter().flatten() {
+          children.push(Self::from((bound, span)));
+        }
+
+        Self {
+          kind: expression.kind(),
+          range,
+          children,
+        }
+      }
       Expression::String(_) => Self {
         kind: expression.kind(),
         range,
@@ -302,6 +495,28 @@ impl From<(&Expression, &Span)> for AstNode {
   }
 }
 
+impl From<(&Pattern, &Span)> for AstNode {
+  fn from(value: (&Pattern, &Span)) -> Self {
+    let (pattern, span) = value;
+
+    let range = Range::from(span);
+
+    let mut children = Vec::new();
+
+    if let Pattern::List(elements, rest) = pattern {
+      for (element, span) in elements.iter().chain(rest.as_deref()) {
+        children.push(Self::from((element, span)));
+      }
+    }
+
+    Self {
+      kind: pattern.kind(),
+      range,
+      children,
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -325,4 +540,27 @@ mod tests {
     assert_eq!(node.range, Range { start: 0, end: 4 });
     assert_eq!(node.children[0].range, Range { start: 2, end: 4 });
   }
+
+  #[test]
+  fn flattens_else_if_chains() {
+    let (ast, span) =
+      val::parse("if (a) { 1 } else if (b) { 2 } else { 3 }").unwrap();
+
+    let node = AstNode::from((&ast, &span));
+
+    assert_eq!(
+      node.children[0]
+        .children
+        .iter()
+        .map(|child| child.kind.as_str())
+        .collect::<Vec<_>>(),
+      [
+        "identifier",
+        "expression",
+        "identifier",
+        "expression",
+        "expression"
+      ]
+    );
+  }
 }
@@ -9,8 +9,10 @@ use {
   serde_wasm_bindgen::to_value,
   std::num::NonZeroUsize,
   val::{
-    Environment, Evaluation, Evaluator, RoundingMode, Span,
-    ast::{AssignmentTarget, Expression, Program, Statement},
+    Environment, Evaluation, Evaluator, Overflow, RoundingMode, Span,
+    ast::{
+      AssignmentTarget, Expression, Parameter, Pattern, Program, Statement,
+    },
   },
   wasm_bindgen::prelude::*,
 };
@@ -62,8 +64,11 @@ pub fn evaluate(input: &str) -> Result<JsValue, JsValue> {
     Ok(ast) => {
       let mut evaluator = Evaluator::from(Environment::new(val::Config {
         digits: NonZeroUsize::new(16).unwrap(),
+        interval: false,
+        overflow: Overflow::Wrapping,
         precision: 53,
         rounding_mode: RoundingMode::FromZero.into(),
+        strict: false,
       }));
 
       match evaluator.evaluate(&ast) {
@@ -44,14 +44,15 @@ impl RangeConverter {
 
 #[cfg(test)]
 mod tests {
-  use super::*;
+  use {super::*, val::SourceId};
 
   #[test]
   fn converts_utf8_bytes_to_utf16_units() {
     #[track_caller]
     fn case(input: &str, span: std::ops::Range<usize>, expected: Range) {
       assert_eq!(
-        RangeConverter::new(input).convert(Range::from(Span::from(span))),
+        RangeConverter::new(input)
+          .convert(Range::from(SourceId::default().span(span))),
         expected,
       );
     }
@@ -33,6 +33,11 @@ pub(crate) struct Arguments {
   expression: Option<String>,
   #[clap(conflicts_with = "expression", help = "File to evaluate")]
   filename: Option<PathBuf>,
+  #[clap(
+    long,
+    help = "Evaluate approximate calculations with interval arithmetic"
+  )]
+  interval: bool,
   #[clap(
     short,
     long,
@@ -40,6 +45,13 @@ pub(crate) struct Arguments {
     help = "Load files before entering the REPL"
   )]
   load: Option<Vec<PathBuf>>,
+  #[clap(
+    long,
+    value_parser = clap::value_parser!(Overflow),
+    default_value = "wrapping",
+    help = "Default overflow behavior for fixed-width integers"
+  )]
+  overflow: Overflow,
   #[clap(
     short,
     long,
@@ -63,34 +75,37 @@ pub(crate) struct Arguments {
     help = "Stack size in MB for evaluations"
   )]
   pub stack_size: NonZeroUsize,
+  #[clap(
+    long,
+    help = "Require variables to be declared with `let` or `const`"
+  )]
+  strict: bool,
 }
 
 impl Arguments {
   fn eval(&self, filename: &PathBuf) -> Result {
     let content = fs::read_to_string(filename)?;
 
-    let filename = filename.to_string_lossy().to_string();
+    let mut evaluator = Evaluator::from(
+      Environment::new(Into::<Config>::into(self)).with_path(filename),
+    );
 
-    let mut evaluator =
-      Evaluator::from(Environment::new(Into::<Config>::into(self)));
+    let source =
+      evaluator.add_source(filename.to_string_lossy().to_string(), &content);
 
-    match parse(&content) {
+    match parse_with_source(&content, source) {
       Ok(ast) => match evaluator.evaluate(&ast) {
         Ok(Evaluation::Exit { code, .. }) => process::exit(code),
         Ok(Evaluation::Value(_)) => Ok(()),
         Err(error) => {
-          error
-            .report(&filename)
-            .eprint((filename.as_str(), Source::from(content)))?;
+          error.report().eprint(evaluator.sources())?;
 
           process::exit(1);
         }
       },
       Err(errors) => {
         for error in errors {
-          error
-            .report(&filename)
-            .eprint((filename.as_str(), Source::from(&content)))?;
+          error.report().eprint(evaluator.sources())?;
         }
 
         process::exit(1);
@@ -98,11 +113,13 @@ impl Arguments {
     }
   }
 
-  fn evaluate_expression(&self, value: String) -> Result {
+  fn evaluate_expression(&self, value: &str) -> Result {
     let mut evaluator =
       Evaluator::from(Environment::new(Into::<Config>::into(self)));
 
-    match parse(&value) {
+    let source = evaluator.add_source("<expression>", value);
+
+    match parse_with_source(value, source) {
       Ok(ast) => match evaluator.evaluate(&ast) {
         Ok(Evaluation::Exit { code, .. }) => process::exit(code),
         Ok(Evaluation::Value(value)) => {
@@ -115,18 +132,14 @@ impl Arguments {
           Ok(())
         }
         Err(error) => {
-          error
-            .report("<expression>")
-            .eprint(("<expression>", Source::from(value)))?;
+          error.report().eprint(evaluator.sources())?;
 
           process::exit(1);
         }
       },
       Err(errors) => {
         for error in errors {
-          error
-            .report("<expression>")
-            .eprint(("<expression>", Source::from(&value)))?;
+          error.report().eprint(evaluator.sources())?;
         }
 
         process::exit(1);
@@ -159,25 +172,22 @@ impl Arguments {
       for filename in filenames {
         let content = fs::read_to_string(filename)?;
 
-        let filename = filename.to_string_lossy().to_string();
+        let source = evaluator
+          .add_source(filename.to_string_lossy().to_string(), &content);
 
-        match parse(&content) {
+        match parse_with_source(&content, source) {
           Ok(ast) => match evaluator.evaluate(&ast) {
             Ok(Evaluation::Exit { code, .. }) => process::exit(code),
             Ok(Evaluation::Value(_)) => {}
             Err(error) => {
-              error
-                .report(&filename)
-                .eprint((filename.as_str(), Source::from(content)))?;
+              error.report().eprint(evaluator.sources())?;
 
               process::exit(1);
             }
           },
           Err(errors) => {
             for error in errors {
-              error
-                .report(&filename)
-                .eprint((filename.as_str(), Source::from(&content)))?;
+              error.report().eprint(evaluator.sources())?;
             }
 
             process::exit(1);
@@ -192,22 +202,20 @@ impl Arguments {
       editor.add_history_entry(&line)?;
       editor.save_history(&history)?;
 
-      match parse(&line) {
+      let source = evaluator.add_source("<input>", &line);
+
+      match parse_with_source(&line, source) {
         Ok(ast) => match evaluator.evaluate(&ast) {
           Ok(Evaluation::Exit { code, .. }) => process::exit(code),
           Ok(Evaluation::Value(value)) if !matches!(value, Value::Null) => {
             println!("{}", value.display(Into::<Config>::into(self)));
           }
           Ok(Evaluation::Value(_)) => {}
-          Err(error) => error
-            .report("<input>")
-            .eprint(("<input>", Source::from(&line)))?,
+          Err(error) => error.report().eprint(evaluator.sources())?,
         },
         Err(errors) => {
           for error in errors {
-            error
-              .report("<input>")
-              .eprint(("<input>", Source::from(&line)))?;
+            error.report().eprint(evaluator.sources())?;
           }
         }
       }
@@ -217,7 +225,7 @@ impl Arguments {
   pub(crate) fn run(self) -> Result {
     match (&self.filename, &self.expression) {
       (Some(filename), _) => self.eval(filename),
-      (_, Some(expression)) => self.evaluate_expression(expression.clone()),
+      (_, Some(expression)) => self.evaluate_expression(expression),
       _ => {
         #[cfg(not(target_family = "wasm"))]
         {
@@ -236,8 +244,11 @@ impl From<&Arguments> for Config {
   fn from(arguments: &Arguments) -> Self {
     Config {
       digits: arguments.digits,
+      interval: arguments.interval,
+      overflow: arguments.overflow,
       precision: arguments.precision.get(),
       rounding_mode: arguments.rounding_mode.into(),
+      strict: arguments.strict,
     }
   }
 }
@@ -329,6 +340,16 @@ mod tests {
     assert_eq!(arguments.filename.unwrap(), PathBuf::from("file.txt"));
   }
 
+  #[test]
+  fn interval() {
+    assert!(!Arguments::parse_from(vec!["program"]).interval);
+
+    let arguments = Arguments::parse_from(vec!["program", "--interval"]);
+
+    assert!(arguments.interval);
+    assert!(Config::from(&arguments).interval);
+  }
+
   #[test]
   fn load_conflicts_with_filename() {
     let result = Arguments::try_parse_from(vec![
@@ -364,4 +385,21 @@ mod tests {
       assert!(result.is_err());
     }
   }
+
+  #[test]
+  fn overflow() {
+    let arguments = Arguments::parse_from(vec!["program"]);
+
+    assert_eq!(arguments.overflow, Overflow::Wrapping);
+
+    let arguments =
+      Arguments::parse_from(vec!["program", "--overflow", "saturating"]);
+
+    assert_eq!(arguments.overflow, Overflow::Saturating);
+
+    assert!(
+      Arguments::try_parse_from(vec!["program", "--overflow", "panic"])
+        .is_err()
+    );
+  }
 }
@@ -37,17 +37,30 @@ pub enum Statement {
   Assignment(Spanned<AssignmentTarget>, Spanned<Expression>),
   Block(Vec<Spanned<Statement>>),
   Break,
+  CompoundAssignment(Spanned<AssignmentTarget>, BinaryOp, Spanned<Expression>),
+  Const(Spanned<AssignmentTarget>, Spanned<Expression>),
   Continue,
   Expression(Spanned<Expression>),
-  For(String, Spanned<Expression>, Vec<Spanned<Statement>>),
-  Function(String, Vec<String>, Vec<Spanned<Statement>>),
-  If(
+  For(
+    Spanned<AssignmentTarget>,
     Spanned<Expression>,
     Vec<Spanned<Statement>>,
+  ),
+  Function(String, Vec<Parameter>, Vec<Spanned<Statement>>),
+  If(
+    Vec<(Spanned<Expression>, Vec<Spanned<Statement>>)>,
     Option<Vec<Spanned<Statement>>>,
   ),
+  Import(Spanned<String>, Option<Spanned<String>>),
+  Let(Spanned<AssignmentTarget>, Option<Spanned<Expression>>),
   Loop(Vec<Spanned<Statement>>),
   Return(Option<Spanned<Expression>>),
+  Throw(Spanned<Expression>),
+  Try(
+    Vec<Spanned<Statement>>,
+    Option<(Spanned<String>, Vec<Spanned<Statement>>)>,
+    Option<Vec<Spanned<Statement>>>,
+  ),
   While(Spanned<Expression>, Vec<Spanned<Statement>>),
 }
 
@@ -58,13 +71,19 @@ impl Statement {
       Statement::Assignment(_, _) => "assignment",
       Statement::Block(_) => "block",
       Statement::Break => "break",
+      Statement::CompoundAssignment(_, _, _) => "compound_assignment",
+      Statement::Const(_, _) => "const",
       Statement::Continue => "continue",
       Statement::Expression(_) => "expression",
       Statement::For(_, _, _) => "for",
       Statement::Function(_, _, _) => "function",
-      Statement::If(_, _, _) => "if",
+      Statement::If(_, _) => "if",
+      Statement::Import(_, _) => "import",
+      Statement::Let(_, _) => "let",
       Statement::Loop(_) => "loop",
       Statement::Return(_) => "return",
+      Statement::Throw(_) => "throw",
+      Statement::Try(_, _, _) => "try",
       Statement::While(_, _) => "while",
     })
   }
@@ -88,15 +107,21 @@ impl Display for Statement {
         )
       }
       Statement::Break => write!(f, "break"),
+      Statement::CompoundAssignment(lhs, op, rhs) => {
+        write!(f, "compound_assignment({op}, {}, {})", lhs.0, rhs.0)
+      }
+      Statement::Const(target, expression) => {
+        write!(f, "const({}, {})", target.0, expression.0)
+      }
       Statement::Continue => write!(f, "continue"),
       Statement::Expression(expression) => {
         write!(f, "expression({})", expression.0)
       }
-      Statement::For(name, iterable, body) => {
+      Statement::For(target, iterable, body) => {
         write!(
           f,
           "for({}, {}, block({}))",
-          name,
+          target.0,
           iterable.0,
           body
             .iter()
@@ -110,7 +135,11 @@ impl Display for Statement {
           f,
           "function({}, [{}], block({}))",
           name,
-          params.join(", "),
+          params
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
           body
             .iter()
             .map(|s| s.0.to_string())
@@ -118,32 +147,45 @@ impl Display for Statement {
             .join(", ")
         )
       }
-      Statement::If(condition, then_branch, else_branch) => {
-        let then_str = then_branch
+      Statement::If(branches, else_branch) => {
+        let mut parts = branches
           .iter()
-          .map(|s| s.0.to_string())
-          .collect::<Vec<_>>()
-          .join(", ");
-
-        match else_branch {
-          Some(else_statements) => {
-            write!(
-              f,
-              "if({}, block({}), block({}))",
-              condition.0,
-              then_str,
-              else_statements
-                .iter()
-                .map(|s| s.0.to_string())
-                .collect::<Vec<_>>()
-                .join(", ")
-            )
-          }
-          None => {
-            write!(f, "if({}, block({}))", condition.0, then_str)
-          }
+          .flat_map(|(condition, body)| {
+            [
+              condition.0.to_string(),
+              format!(
+                "block({})",
+                body
+                  .iter()
+                  .map(|s| s.0.to_string())
+                  .collect::<Vec<_>>()
+                  .join(", ")
+              ),
+            ]
+          })
+          .collect::<Vec<_>>();
+
+        if let Some(else_statements) = else_branch {
+          parts.push(format!(
+            "block({})",
+            else_statements
+              .iter()
+              .map(|s| s.0.to_string())
+              .collect::<Vec<_>>()
+              .join(", ")
+          ));
         }
+
+        write!(f, "if({})", parts.join(", "))
       }
+      Statement::Import(path, alias) => match alias {
+        Some(alias) => write!(f, "import({:?}, {})", path.0, alias.0),
+        None => write!(f, "import({:?})", path.0),
+      },
+      Statement::Let(target, expression) => match expression {
+        Some(expression) => write!(f, "let({}, {})", target.0, expression.0),
+        None => write!(f, "let({})", target.0),
+      },
       Statement::Loop(body) => {
         write!(
           f,
@@ -159,6 +201,31 @@ impl Display for Statement {
         Some(expression) => write!(f, "return({})", expression.0),
         None => write!(f, "return()"),
       },
+      Statement::Throw(expression) => write!(f, "throw({})", expression.0),
+      Statement::Try(body, catch, finally) => {
+        let block = |statements: &[Spanned<Statement>]| {
+          format!(
+            "block({})",
+            statements
+              .iter()
+              .map(|s| s.0.to_string())
+              .collect::<Vec<_>>()
+              .join(", ")
+          )
+        };
+
+        let mut parts = vec![block(body)];
+
+        if let Some((name, handler)) = catch {
+          parts.push(format!("catch({}, {})", name.0, block(handler)));
+        }
+
+        if let Some(finally) = finally {
+          parts.push(format!("finally({})", block(finally)));
+        }
+
+        write!(f, "try({})", parts.join(", "))
+      }
       Statement::While(condition, body) => {
         write!(
           f,
@@ -178,7 +245,9 @@ impl Display for Statement {
 #[derive(Debug, Clone)]
 pub enum AssignmentTarget {
   Identifier(String),
+  List(Vec<Spanned<Self>>),
   ListAccess(Box<Spanned<Self>>, Box<Spanned<Expression>>),
+  Slice(Box<Spanned<Self>>, Slice),
 }
 
 impl AssignmentTarget {
@@ -186,7 +255,9 @@ impl AssignmentTarget {
   pub fn kind(&self) -> String {
     String::from(match self {
       AssignmentTarget::Identifier(_) => "identifier",
+      AssignmentTarget::List(_) => "list",
       AssignmentTarget::ListAccess(_, _) => "list_access",
+      AssignmentTarget::Slice(_, _) => "slice",
     })
   }
 }
@@ -194,19 +265,22 @@ impl AssignmentTarget {
 impl AssignmentTarget {
   pub(crate) fn indices(&self) -> Vec<&Spanned<Expression>> {
     match self {
-      AssignmentTarget::Identifier(_) => Vec::new(),
+      AssignmentTarget::Identifier(_) | AssignmentTarget::List(_) => Vec::new(),
       AssignmentTarget::ListAccess(base, index) => {
         let mut indices = base.0.indices();
         indices.push(index);
         indices
       }
+      AssignmentTarget::Slice(base, _) => base.0.indices(),
     }
   }
 
   pub(crate) fn root(&self, span: Span) -> (&str, Span) {
     match self {
       AssignmentTarget::Identifier(name) => (name, span),
-      AssignmentTarget::ListAccess(base, _) => base.0.root(base.1),
+      AssignmentTarget::ListAccess(base, _)
+      | AssignmentTarget::Slice(base, _) => base.0.root(base.1),
+      AssignmentTarget::List(_) => unreachable!(),
     }
   }
 }
@@ -217,24 +291,173 @@ impl Display for AssignmentTarget {
       AssignmentTarget::Identifier(identifier) => {
         write!(f, "identifier({identifier})")
       }
+      AssignmentTarget::List(targets) => {
+        write!(
+          f,
+          "list({})",
+          targets
+            .iter()
+            .map(|target| target.0.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+        )
+      }
       AssignmentTarget::ListAccess(list, index) => {
         write!(f, "list_access({}, {})", list.0, index.0)
       }
+      AssignmentTarget::Slice(list, slice) => {
+        write!(f, "slice({}, {slice})", list.0)
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum Parameter {
+  Default(String, Spanned<Expression>),
+  Positional(String),
+  Rest(String),
+}
+
+impl Parameter {
+  #[must_use]
+  pub fn name(&self) -> &str {
+    match self {
+      Parameter::Default(name, _)
+      | Parameter::Positional(name)
+      | Parameter::Rest(name) => name,
+    }
+  }
+}
+
+impl Display for Parameter {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Parameter::Default(name, default) => write!(f, "{name} = {}", default.0),
+      Parameter::Positional(name) => write!(f, "{name}"),
+      Parameter::Rest(name) => write!(f, "...{name}"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+  pub body: Spanned<Expression>,
+  pub guard: Option<Spanned<Expression>>,
+  pub pattern: Spanned<Pattern>,
+}
+
+impl Display for MatchArm {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match &self.guard {
+      Some(guard) => {
+        write!(f, "arm({}, {}, {})", self.pattern.0, guard.0, self.body.0)
+      }
+      None => write!(f, "arm({}, {})", self.pattern.0, self.body.0),
     }
   }
 }
 
+#[derive(Debug, Clone)]
+pub enum Pattern {
+  Binding(String),
+  List(Vec<Spanned<Self>>, Option<Box<Spanned<Self>>>),
+  Literal(Expression),
+  Type(Option<String>, String),
+  Wildcard,
+}
+
+impl Pattern {
+  #[must_use]
+  pub fn kind(&self) -> String {
+    String::from(match self {
+      Pattern::Binding(_) => "binding",
+      Pattern::List(_, _) => "list_pattern",
+      Pattern::Literal(_) => "literal",
+      Pattern::Type(_, _) => "type_pattern",
+      Pattern::Wildcard => "wildcard",
+    })
+  }
+}
+
+impl Display for Pattern {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Pattern::Binding(name) => write!(f, "binding({name})"),
+      Pattern::List(elements, rest) => write!(
+        f,
+        "list_pattern({})",
+        elements
+          .iter()
+          .map(|element| element.0.to_string())
+          .chain(rest.iter().map(|rest| format!("...{}", rest.0)))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+      Pattern::Literal(expression) => write!(f, "{expression}"),
+      Pattern::Type(Some(name), type_name) => {
+        write!(f, "type_pattern({name}, {type_name})")
+      }
+      Pattern::Type(None, type_name) => {
+        write!(f, "type_pattern(_, {type_name})")
+      }
+      Pattern::Wildcard => write!(f, "wildcard"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Slice {
+  pub start: Option<Box<Spanned<Expression>>>,
+  pub step: Option<Box<Spanned<Expression>>>,
+  pub stop: Option<Box<Spanned<Expression>>>,
+}
+
+impl Slice {
+  #[must_use]
+  pub fn bounds(&self) -> [Option<&Spanned<Expression>>; 3] {
+    [
+      self.start.as_deref(),
+      self.stop.as_deref(),
+      self.step.as_deref(),
+    ]
+  }
+}
+
+impl Display for Slice {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      self
+        .bounds()
+        .map(
+          |bound| bound.map_or_else(|| "_".into(), |bound| bound.0.to_string())
+        )
+        .join(", ")
+    )
+  }
+}
+
 #[derive(Debug, Clone)]
 pub enum UnaryOp {
+  Abs,
+  BitwiseNot,
+  Factorial,
   Negate,
   Not,
+  SquareRoot,
 }
 
 impl Display for UnaryOp {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
     match self {
+      UnaryOp::Abs => write!(f, "abs"),
+      UnaryOp::BitwiseNot => write!(f, "~"),
+      UnaryOp::Factorial => write!(f, "factorial"),
       UnaryOp::Negate => write!(f, "-"),
       UnaryOp::Not => write!(f, "!"),
+      UnaryOp::SquareRoot => write!(f, "√"),
     }
   }
 }
@@ -242,10 +465,16 @@ impl Display for UnaryOp {
 #[derive(Debug, Clone)]
 pub enum BinaryOp {
   Add,
+  BitwiseAnd,
+  BitwiseOr,
+  BitwiseXor,
+  Coalesce,
   Divide,
   Equal,
   GreaterThan,
   GreaterThanEqual,
+  Hadamard,
+  In,
   LessThan,
   LessThanEqual,
   LogicalAnd,
@@ -254,6 +483,10 @@ pub enum BinaryOp {
   Multiply,
   NotEqual,
   Power,
+  Range,
+  RangeInclusive,
+  ShiftLeft,
+  ShiftRight,
   Subtract,
 }
 
@@ -261,10 +494,16 @@ impl Display for BinaryOp {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
     match self {
       BinaryOp::Add => write!(f, "+"),
+      BinaryOp::BitwiseAnd => write!(f, "&"),
+      BinaryOp::BitwiseOr => write!(f, "|"),
+      BinaryOp::BitwiseXor => write!(f, "~"),
+      BinaryOp::Coalesce => write!(f, "??"),
       BinaryOp::Divide => write!(f, "/"),
       BinaryOp::Equal => write!(f, "=="),
       BinaryOp::GreaterThan => write!(f, ">"),
       BinaryOp::GreaterThanEqual => write!(f, ">="),
+      BinaryOp::Hadamard => write!(f, ".*"),
+      BinaryOp::In => write!(f, "in"),
       BinaryOp::LessThan => write!(f, "<"),
       BinaryOp::LessThanEqual => write!(f, "<="),
       BinaryOp::LogicalAnd => write!(f, "&&"),
@@ -273,6 +512,10 @@ impl Display for BinaryOp {
       BinaryOp::Multiply => write!(f, "*"),
       BinaryOp::NotEqual => write!(f, "!="),
       BinaryOp::Power => write!(f, "^"),
+      BinaryOp::Range => write!(f, ".."),
+      BinaryOp::RangeInclusive => write!(f, "..="),
+      BinaryOp::ShiftLeft => write!(f, "<<"),
+      BinaryOp::ShiftRight => write!(f, ">>"),
       BinaryOp::Subtract => write!(f, "-"),
     }
   }
@@ -282,13 +525,24 @@ impl Display for BinaryOp {
 pub enum Expression {
   BinaryOp(BinaryOp, Box<Spanned<Self>>, Box<Spanned<Self>>),
   Boolean(bool),
-  Function(Vec<String>, Vec<Spanned<Statement>>),
-  FunctionCall(Box<Spanned<Self>>, Vec<Spanned<Self>>),
+  Comparison(Box<Spanned<Self>>, Vec<(BinaryOp, Spanned<Self>)>),
+  Conditional(Box<Spanned<Self>>, Box<Spanned<Self>>, Box<Spanned<Self>>),
+  Function(Vec<Parameter>, Vec<Spanned<Statement>>),
+  FunctionCall(
+    Box<Spanned<Self>>,
+    Vec<Spanned<Self>>,
+    Vec<(Spanned<String>, Spanned<Self>)>,
+  ),
   Identifier(String),
+  Interpolation(Vec<Spanned<Self>>),
   List(Vec<Spanned<Self>>),
   ListAccess(Box<Spanned<Self>>, Box<Spanned<Self>>),
+  Map(Vec<(Spanned<Self>, Spanned<Self>)>),
+  Match(Box<Spanned<Self>>, Vec<MatchArm>),
+  Member(Box<Spanned<Self>>, Spanned<String>),
   Null,
   Number(Number),
+  Slice(Box<Spanned<Self>>, Slice),
   String(String),
   UnaryOp(UnaryOp, Box<Spanned<Self>>),
 }
@@ -299,13 +553,20 @@ impl Expression {
     String::from(match self {
       Expression::BinaryOp(_, _, _) => "binary_op",
       Expression::Boolean(_) => "boolean",
+      Expression::Comparison(_, _) => "comparison",
+      Expression::Conditional(_, _, _) => "conditional",
       Expression::Function(_, _) => "function",
-      Expression::FunctionCall(_, _) => "function_call",
+      Expression::FunctionCall(_, _, _) => "function_call",
       Expression::Identifier(_) => "identifier",
+      Expression::Interpolation(_) => "interpolation",
       Expression::List(_) => "list",
       Expression::ListAccess(_, _) => "list_access",
+      Expression::Map(_) => "map",
+      Expression::Match(_, _) => "match",
+      Expression::Member(_, _) => "member",
       Expression::Null => "null",
       Expression::Number(_) => "number",
+      Expression::Slice(_, _) => "slice",
       Expression::String(_) => "string",
       Expression::UnaryOp(_, _) => "unary_op",
     })
@@ -319,11 +580,31 @@ impl Display for Expression {
         write!(f, "binary_op({}, {}, {})", op, lhs.0, rhs.0)
       }
       Expression::Boolean(boolean) => write!(f, "boolean({boolean})"),
+      Expression::Comparison(first, rest) => {
+        write!(f, "comparison({}", first.0)?;
+
+        for (op, operand) in rest {
+          write!(f, ", {op}, {}", operand.0)?;
+        }
+
+        write!(f, ")")
+      }
+      Expression::Conditional(condition, then_branch, else_branch) => {
+        write!(
+          f,
+          "conditional({}, {}, {})",
+          condition.0, then_branch.0, else_branch.0
+        )
+      }
       Expression::Function(params, body) => {
         write!(
           f,
           "function([{}], block({}))",
-          params.join(", "),
+          params
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
           body
             .iter()
             .map(|s| s.0.to_string())
@@ -331,18 +612,33 @@ impl Display for Expression {
             .join(", ")
         )
       }
-      Expression::FunctionCall(function, arguments) => {
+      Expression::FunctionCall(function, arguments, named) => {
         write!(f, "function_call({}", function.0)?;
 
         for argument in arguments {
           write!(f, ", {}", argument.0)?;
         }
 
+        for ((name, _), argument) in named {
+          write!(f, ", {name}: {}", argument.0)?;
+        }
+
         write!(f, ")")
       }
       Expression::Identifier(identifier) => {
         write!(f, "identifier({identifier})")
       }
+      Expression::Interpolation(parts) => {
+        write!(
+          f,
+          "interpolation({})",
+          parts
+            .iter()
+            .map(|part| part.0.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+        )
+      }
       Expression::List(list) => {
         write!(
           f,
@@ -357,9 +653,36 @@ impl Display for Expression {
       Expression::ListAccess(list, index) => {
         write!(f, "list_access({}, {})", list.0, index.0)
       }
+      Expression::Map(entries) => {
+        write!(
+          f,
+          "map({})",
+          entries
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key.0, value.0))
+            .collect::<Vec<_>>()
+            .join(", ")
+        )
+      }
+      Expression::Match(subject, arms) => write!(
+        f,
+        "match({}, {})",
+        subject.0,
+        arms
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+      Expression::Member(base, name) => {
+        write!(f, "member({}, {})", base.0, name.0)
+      }
       Expression::Null => write!(f, "null"),
       Expression::Number(number) => write!(f, "number({number})"),
-      Expression::String(string) => write!(f, "string(\"{string}\")"),
+      Expression::Slice(list, slice) => {
+        write!(f, "slice({}, {slice})", list.0)
+      }
+      Expression::String(string) => write!(f, "string({string:?})"),
       Expression::UnaryOp(op, expr) => {
         write!(f, "unary_op({}, {})", op, expr.0)
       }
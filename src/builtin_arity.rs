@@ -3,6 +3,7 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub enum BuiltinArity {
   Any,
+  AtLeast(usize),
   Exact(usize),
   Range(usize, usize),
 }
@@ -11,6 +12,7 @@ impl BuiltinArity {
   fn accepts(self, len: usize) -> bool {
     match self {
       Self::Any => true,
+      Self::AtLeast(min) => len >= min,
       Self::Exact(expected) => len == expected,
       Self::Range(min, max) => len >= min && len <= max,
     }
@@ -35,6 +37,8 @@ impl BuiltinArity {
   fn expected(self) -> String {
     match self {
       Self::Any => "any number of arguments".into(),
+      Self::AtLeast(1) => "at least 1 argument".into(),
+      Self::AtLeast(min) => format!("at least {min} arguments"),
       Self::Exact(1) => "1 argument".into(),
       Self::Exact(expected) => format!("{expected} arguments"),
       Self::Range(min, max) if min + 1 == max => {
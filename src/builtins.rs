@@ -5,6 +5,10 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     name: "e",
     value: constant_e,
   },
+  Builtin::Constant {
+    name: "i",
+    value: constant_i,
+  },
   Builtin::Constant {
     name: "phi",
     value: constant_phi,
@@ -17,6 +21,18 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     name: "tau",
     value: constant_tau,
   },
+  Builtin::Constant {
+    name: "π",
+    value: constant_pi,
+  },
+  Builtin::Constant {
+    name: "τ",
+    value: constant_tau,
+  },
+  Builtin::Constant {
+    name: "φ",
+    value: constant_phi,
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(abs),
@@ -37,6 +53,11 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(acsc),
     name: "acsc",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(antiderivative),
+    name: "antiderivative",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(2),
     function: BuiltinFunction::Fallible(append),
@@ -47,6 +68,11 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(arc),
     name: "arc",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(arg),
+    name: "arg",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(asec),
@@ -57,6 +83,16 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(asin),
     name: "asin",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(binomial),
+    name: "binomial",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(bit_length),
+    name: "bit_length",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(r#bool),
@@ -67,6 +103,11 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(ceil),
     name: "ceil",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(conj),
+    name: "conj",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(cos),
@@ -87,16 +128,56 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(csc),
     name: "csc",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(derivative),
+    name: "derivative",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(det),
+    name: "det",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(divisors),
+    name: "divisors",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(e),
     name: "e",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(eigenvalues),
+    name: "eigenvalues",
+  },
   Builtin::Function {
     arity: BuiltinArity::Range(0, 1),
     function: BuiltinFunction::Fallible(exit),
     name: "exit",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(e),
+    name: "exp",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(factor),
+    name: "factor",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(factorial),
+    name: "factorial",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(fibonacci),
+    name: "fibonacci",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(float),
@@ -112,6 +193,46 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(gcd),
     name: "gcd",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(hadamard),
+    name: "hadamard",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(has),
+    name: "has",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(i16),
+    name: "i16",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(i32),
+    name: "i32",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(i64),
+    name: "i64",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(i8),
+    name: "i8",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(identity),
+    name: "identity",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(im),
+    name: "im",
+  },
   Builtin::Function {
     arity: BuiltinArity::Range(0, 1),
     function: BuiltinFunction::Fallible(input),
@@ -122,11 +243,41 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(int),
     name: "int",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(interval),
+    name: "interval",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(inverse),
+    name: "inverse",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(iroot),
+    name: "iroot",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(is_prime),
+    name: "is_prime",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(isqrt),
+    name: "isqrt",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(2),
     function: BuiltinFunction::Fallible(join),
     name: "join",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(keys),
+    name: "keys",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(2),
     function: BuiltinFunction::Fallible(lcm),
@@ -157,6 +308,36 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(log2),
     name: "log2",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(lower),
+    name: "lower",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(matrix),
+    name: "matrix",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(next_prime),
+    name: "next_prime",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::AtLeast(1),
+    function: BuiltinFunction::Fallible(poly),
+    name: "poly",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(polyval),
+    name: "polyval",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(popcount),
+    name: "popcount",
+  },
   Builtin::Function {
     arity: BuiltinArity::Any,
     function: BuiltinFunction::Fallible(print),
@@ -177,11 +358,36 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(range),
     name: "range",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(rank),
+    name: "rank",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(re),
+    name: "re",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(remove),
+    name: "remove",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(roots),
+    name: "roots",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(sec),
     name: "sec",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Range(2, 3),
+    function: BuiltinFunction::Fallible(set_bit),
+    name: "set_bit",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(1),
     function: BuiltinFunction::Fallible(sin),
@@ -192,6 +398,11 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(sinh),
     name: "sinh",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(solve),
+    name: "solve",
+  },
   Builtin::Function {
     arity: BuiltinArity::Exact(2),
     function: BuiltinFunction::Fallible(split),
@@ -217,40 +428,91 @@ pub(crate) const BUILTINS: &[Builtin] = &[
     function: BuiltinFunction::Fallible(tanh),
     name: "tanh",
   },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(2),
+    function: BuiltinFunction::Fallible(test_bit),
+    name: "test_bit",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(totient),
+    name: "totient",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(trailing_zeros),
+    name: "trailing_zeros",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(transpose),
+    name: "transpose",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(u16),
+    name: "u16",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(u32),
+    name: "u32",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(u64),
+    name: "u64",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Range(1, 2),
+    function: BuiltinFunction::Fallible(u8),
+    name: "u8",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(upper),
+    name: "upper",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::Exact(1),
+    function: BuiltinFunction::Fallible(values),
+    name: "values",
+  },
+  Builtin::Function {
+    arity: BuiltinArity::AtLeast(1),
+    function: BuiltinFunction::Fallible(vector),
+    name: "vector",
+  },
 ];
 
 fn abs<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
-  Ok(Value::Number(
-    payload.arguments[0].number(payload.span)?.abs(),
-  ))
+  match &payload.arguments[0] {
+    Value::Fixed(fixed) if fixed.to_integer().is_negative() => fixed
+      .negate()
+      .map(Value::Fixed)
+      .map_err(|error| error.with_span(payload.span)),
+    Value::Fixed(fixed) => Ok(Value::Fixed(*fixed)),
+    value => Ok(Value::Number(
+      value.number(payload.span)?.abs(payload.config),
+    )),
+  }
 }
 
 fn acos<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let argument = payload.arguments[0].number(payload.span)?;
 
-  if argument < &Number::from(-1_i64) || argument > &Number::from(1_i64) {
-    return Err(Error::new(
-      payload.span,
-      "acos argument must be between -1 and 1",
-    ));
-  }
-
-  Ok(Value::Number(argument.acos(payload.config)))
+  argument
+    .acos(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
 }
 
 fn acot<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let argument = payload.arguments[0].number(payload.span)?;
 
-  let pi_div_2 = Number::Approx(
-    Float::with_val_round(
-      payload.config.precision(),
-      Constant::Pi,
-      payload.config.rounding_mode,
-    )
-    .0,
-  )
-  .div(&Number::from(2_i64), payload.config)
-  .unwrap();
+  let pi_div_2 = Number::pi(payload.config)
+    .div(&Number::from(2_i64), payload.config)
+    .unwrap();
 
   Ok(Value::Number(
     pi_div_2.sub(&argument.atan(payload.config), payload.config),
@@ -260,18 +522,24 @@ fn acot<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
 fn acsc<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let argument = payload.arguments[0].number(payload.span)?;
 
-  if argument.abs() < Number::from(1_i64) {
-    return Err(Error::new(
-      payload.span,
-      "acsc argument must have absolute value at least 1",
-    ));
-  }
-
   let reciprocal = Number::from(1_i64)
     .div(argument, payload.config)
     .map_err(|error| error.with_span(payload.span))?;
 
-  Ok(Value::Number(reciprocal.asin(payload.config)))
+  reciprocal
+    .asin(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn antiderivative<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Polynomial(
+    payload.arguments[0]
+      .polynomial(payload.span)?
+      .antiderivative(payload.config),
+  ))
 }
 
 fn append<'a>(
@@ -292,34 +560,80 @@ fn arc<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   ))
 }
 
+fn arg<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0]
+      .number(payload.span)?
+      .arg(payload.config),
+  ))
+}
+
 fn asec<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let argument = payload.arguments[0].number(payload.span)?;
 
-  if argument.abs() < Number::from(1_i64) {
-    return Err(Error::new(
-      payload.span,
-      "asec argument must have absolute value at least 1",
-    ));
-  }
-
   let reciprocal = Number::from(1_i64)
     .div(argument, payload.config)
     .map_err(|error| error.with_span(payload.span))?;
 
-  Ok(Value::Number(reciprocal.acos(payload.config)))
+  reciprocal
+    .acos(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
 }
 
 fn asin<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let argument = payload.arguments[0].number(payload.span)?;
 
-  if argument < &Number::from(-1_i64) || argument > &Number::from(1_i64) {
+  argument
+    .asin(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn binomial<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let n = payload.arguments[0].integer(payload.span)?;
+
+  let k = payload.arguments[1].integer(payload.span)?;
+
+  if k.is_negative() {
+    return Ok(Value::Number(Number::from(0_i64)));
+  }
+
+  let Some(k) = k.to_u32() else {
     return Err(Error::new(
       payload.span,
-      "asin argument must be between -1 and 1",
+      format!("Binomial coefficient with k = {k} is too large"),
     ));
-  }
+  };
+
+  Ok(Value::Number(Number::from(n.binomial(k))))
+}
+
+fn bit_index(
+  payload: &BuiltinFunctionPayload<'_>,
+  name: &str,
+) -> Result<u32, Error> {
+  payload.arguments[1]
+    .integer(payload.span)?
+    .to_u32()
+    .ok_or_else(|| {
+      Error::new(
+        payload.span,
+        format!("Bit index to `{name}` must be between 0 and {}", u32::MAX),
+      )
+    })
+}
 
-  Ok(Value::Number(argument.asin(payload.config)))
+fn bit_length<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(Number::from(i64::from(
+    payload.arguments[0]
+      .integer(payload.span)?
+      .significant_bits(),
+  ))))
 }
 
 fn r#bool<'a>(
@@ -329,11 +643,15 @@ fn r#bool<'a>(
 
   match value {
     Value::Boolean(b) => Ok(Value::Boolean(*b)),
+    Value::Fixed(fixed) => Ok(Value::Boolean(fixed.to_integer() != 0)),
     Value::Number(n) => Ok(Value::Boolean(!n.is_zero())),
     Value::String(s) => Ok(Value::Boolean(!s.is_empty())),
     Value::List(items) => Ok(Value::Boolean(!items.is_empty())),
+    Value::Map(map) => Ok(Value::Boolean(!map.is_empty())),
     Value::Null => Ok(Value::Boolean(false)),
-    Value::Function(_) => Err(Error::new(
+    Value::Polynomial(polynomial) => Ok(Value::Boolean(!polynomial.is_zero())),
+    Value::Range(range) => Ok(Value::Boolean(!range.is_empty())),
+    Value::Error(_) | Value::Function(_) | Value::Matrix(_) => Err(Error::new(
       payload.span,
       format!("Cannot convert {} to bool", value.type_name()),
     )),
@@ -346,26 +664,29 @@ fn ceil<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   ))
 }
 
+fn conj<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0].number(payload.span)?.conj(),
+  ))
+}
+
 fn constant_e(config: Config) -> Number {
   Number::e(config)
 }
 
+fn constant_i(_config: Config) -> Number {
+  Number::complex(Number::from(0_i64), Number::from(1_i64))
+}
+
 fn constant_phi(config: Config) -> Number {
   Number::from(1_i64)
-    .add(&Number::from(5_i64).sqrt(config), config)
+    .add(&Number::from(5_i64).sqrt(config).unwrap(), config)
     .div(&Number::from(2_i64), config)
     .unwrap()
 }
 
 fn constant_pi(config: Config) -> Number {
-  Number::Approx(
-    Float::with_val_round(
-      config.precision(),
-      Constant::Pi,
-      config.rounding_mode,
-    )
-    .0,
-  )
+  Number::pi(config)
 }
 
 fn constant_tau(config: Config) -> Number {
@@ -424,6 +745,36 @@ fn csc<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
     .map_err(|error| error.with_span(payload.span))
 }
 
+fn derivative<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Polynomial(
+    payload.arguments[0]
+      .polynomial(payload.span)?
+      .derivative(payload.config),
+  ))
+}
+
+fn det<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .matrix(payload.span)?
+    .determinant(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn divisors<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::List(
+    factorization(payload, "divisors")?
+      .divisors()
+      .into_iter()
+      .map(|divisor| Value::Number(Number::from(divisor)))
+      .collect(),
+  ))
+}
+
 fn e<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   Ok(Value::Number(
     payload.arguments[0]
@@ -432,6 +783,18 @@ fn e<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   ))
 }
 
+fn eigenvalues<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .matrix(payload.span)?
+    .eigenvalues(payload.config)
+    .map(|eigenvalues| {
+      Value::List(eigenvalues.into_iter().map(Value::Number).collect())
+    })
+    .map_err(|error| error.with_span(payload.span))
+}
+
 fn exit<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let code = if payload.arguments.is_empty() {
     0
@@ -462,17 +825,119 @@ fn exit<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   })
 }
 
+fn factor<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let pair = |prime: Integer, exponent: u32| {
+    Value::List(vec![
+      Value::Number(Number::from(prime)),
+      Value::Number(Number::from(i64::from(exponent))),
+    ])
+  };
+
+  let mut factors = Vec::new();
+
+  if payload.arguments[0].integer(payload.span)?.is_negative() {
+    factors.push(pair(Integer::from(-1), 1));
+  }
+
+  factors.extend(
+    factorization(payload, "factor")?
+      .factors()
+      .iter()
+      .map(|(prime, exponent)| pair(prime.clone(), *exponent)),
+  );
+
+  Ok(Value::List(factors))
+}
+
+fn factorial<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Number::from(payload.arguments[0].integer(payload.span)?)
+    .factorial(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn factorization(
+  payload: &BuiltinFunctionPayload<'_>,
+  name: &str,
+) -> Result<Factorization, Error> {
+  let n = payload.arguments[0].integer(payload.span)?;
+
+  if n.is_zero() {
+    return Err(Error::new(
+      payload.span,
+      format!("Argument to `{name}` must be a non-zero integer"),
+    ));
+  }
+
+  Ok(Factorization::new(&n))
+}
+
+fn fibonacci<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let n = payload.arguments[0].integer(payload.span)?;
+
+  let Some(index) = n.clone().abs().to_u32() else {
+    return Err(Error::new(
+      payload.span,
+      format!("Fibonacci number {n} is too large"),
+    ));
+  };
+
+  let fibonacci = Integer::from(Integer::fibonacci(index));
+
+  Ok(Value::Number(Number::from(
+    if n.is_negative() && index % 2 == 0 {
+      -fibonacci
+    } else {
+      fibonacci
+    },
+  )))
+}
+
+fn fixed<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+  ty: FixedType,
+) -> Result<Value<'a>, Error> {
+  let value = &payload.arguments[0];
+
+  let overflow = match (payload.arguments.get(1), value) {
+    (Some(overflow), _) => overflow
+      .string(payload.span)?
+      .parse::<Overflow>()
+      .map_err(|error| Error::new(payload.span, error))?,
+    (None, Value::Fixed(fixed)) => fixed.overflow(),
+    (None, _) => payload.config.overflow,
+  };
+
+  let integer = value.integer(payload.span)?;
+
+  Fixed::new(ty, integer.clone(), overflow)
+    .map(Value::Fixed)
+    .ok_or_else(|| {
+      Error::new(payload.span, format!("{integer} does not fit in {ty}"))
+    })
+}
+
 fn float<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let value = &payload.arguments[0];
 
   match value {
+    Value::Fixed(fixed) => Ok(Value::Number(
+      Number::from(*fixed).to_approx(payload.config),
+    )),
     Value::Number(number) => {
       Ok(Value::Number(number.to_approx(payload.config)))
     }
     Value::String(s) => Number::try_from(s.as_ref())
       .map(|number| Value::Number(number.to_approx(payload.config)))
-      .map_err(|_| {
-        Error::new(payload.span, format!("Cannot convert '{s}' to float"))
+      .map_err(|error| match error {
+        Error::ExponentTooLarge => error.with_span(payload.span),
+        _ => Error::new(payload.span, format!("Cannot convert '{s}' to float")),
       }),
     Value::Boolean(b) => {
       Ok(Value::Number(Number::from(*b).to_approx(payload.config)))
@@ -491,6 +956,19 @@ fn floor<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
 }
 
 fn gcd<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  if let [Value::Polynomial(_), _] | [_, Value::Polynomial(_)] =
+    payload.arguments.as_slice()
+  {
+    return payload.arguments[0]
+      .polynomial(payload.span)?
+      .gcd(
+        &*payload.arguments[1].polynomial(payload.span)?,
+        payload.config,
+      )
+      .map(Value::Polynomial)
+      .map_err(|error| error.with_span(payload.span));
+  }
+
   let Some(a) = payload.arguments[0].number(payload.span)?.to_integer() else {
     return Err(Error::new(
       payload.span,
@@ -511,6 +989,61 @@ fn gcd<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   Ok(Value::Number(Number::from(a.gcd(&b))))
 }
 
+fn hadamard<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .matrix(payload.span)?
+    .hadamard(&*payload.arguments[1].matrix(payload.span)?, payload.config)
+    .map(Value::Matrix)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn has<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  let map = payload.arguments[0].map(payload.span)?;
+
+  let key = payload.arguments[1].key(payload.span)?;
+
+  Ok(Value::Boolean(map.contains_key(&key)))
+}
+
+fn i16<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::I16)
+}
+
+fn i32<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::I32)
+}
+
+fn i64<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::I64)
+}
+
+fn i8<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::I8)
+}
+
+fn identity<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  match payload.arguments[0]
+    .number(payload.span)?
+    .to_non_negative_usize()
+  {
+    Some(size) if size > 0 => Ok(Value::Matrix(Matrix::identity(size))),
+    _ => Err(Error::new(
+      payload.span,
+      "Identity matrix size must be a positive integer",
+    )),
+  }
+}
+
+fn im<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0].number(payload.span)?.im(),
+  ))
+}
+
 fn input<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   use std::io::{self, BufRead, Write};
 
@@ -546,11 +1079,17 @@ fn int<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let value = &payload.arguments[0];
 
   match value {
+    Value::Fixed(fixed) => Ok(Value::Number(Number::from(*fixed))),
+    Value::Number(number) if number.is_complex() => Err(Error::new(
+      payload.span,
+      format!("Cannot convert complex number {number} to int"),
+    )),
     Value::Number(number) => Ok(Value::Number(number.floor())),
     Value::String(s) => Number::try_from(s.as_ref())
       .map(|number| Value::Number(number.floor()))
-      .map_err(|_| {
-        Error::new(payload.span, format!("Cannot convert '{s}' to int"))
+      .map_err(|error| match error {
+        Error::ExponentTooLarge => error.with_span(payload.span),
+        _ => Error::new(payload.span, format!("Cannot convert '{s}' to int")),
       }),
     Value::Boolean(b) => Ok(Value::Number(Number::from(*b))),
     _ => Err(Error::new(
@@ -560,6 +1099,81 @@ fn int<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   }
 }
 
+fn interval<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let lower = payload.arguments[0].number(payload.span)?;
+
+  let upper = match payload.arguments.get(1) {
+    Some(upper) => upper.number(payload.span)?,
+    None => lower,
+  };
+
+  Number::interval(lower, upper, payload.config)
+    .map(Value::Number)
+    .ok_or_else(|| {
+      Error::new(
+        payload.span,
+        format!("Cannot make an interval from {lower} to {upper}"),
+      )
+    })
+}
+
+fn inverse<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .matrix(payload.span)?
+    .inverse(payload.config)
+    .map(Value::Matrix)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn iroot<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  let n = payload.arguments[0].integer(payload.span)?;
+
+  let Some(k) = payload.arguments[1]
+    .integer(payload.span)?
+    .to_u32()
+    .filter(|k| *k > 0)
+  else {
+    return Err(Error::new(
+      payload.span,
+      format!("Root index to `iroot` must be between 1 and {}", u32::MAX),
+    ));
+  };
+
+  if n.is_negative() && k % 2 == 0 {
+    return Err(Error::new(
+      payload.span,
+      "Cannot take an even root of a negative integer",
+    ));
+  }
+
+  Ok(Value::Number(Number::from(n.root(k))))
+}
+
+fn is_prime<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Boolean(Factorization::is_prime(
+    &payload.arguments[0].integer(payload.span)?,
+  )))
+}
+
+fn isqrt<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  let n = payload.arguments[0].integer(payload.span)?;
+
+  if n.is_negative() {
+    return Err(Error::new(
+      payload.span,
+      "Cannot take the integer square root of a negative integer",
+    ));
+  }
+
+  Ok(Value::Number(Number::from(n.sqrt())))
+}
+
 fn join<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let list = payload.arguments[0].list(payload.span)?;
 
@@ -577,6 +1191,12 @@ fn join<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   Ok(Value::String(Cow::Owned(joined_string)))
 }
 
+fn keys<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  let map = payload.arguments[0].map(payload.span)?;
+
+  Ok(Value::List(map.keys().cloned().map(Value::from).collect()))
+}
+
 fn lcm<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let Some(a) = payload.arguments[0].number(payload.span)?.to_integer() else {
     return Err(Error::new(
@@ -604,6 +1224,11 @@ fn len<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   match value {
     Value::String(s) => Ok(Value::Number(Number::from(s.chars().count()))),
     Value::List(items) => Ok(Value::Number(Number::from(items.len()))),
+    Value::Map(map) => Ok(Value::Number(Number::from(map.len()))),
+    Value::Matrix(matrix) => {
+      Ok(Value::Number(Number::from(matrix.to_list().len())))
+    }
+    Value::Range(range) => Ok(Value::Number(Number::from(range.len()))),
     _ => Err(Error::new(
       payload.span,
       format!("Cannot get length of {}", value.type_name()),
@@ -616,6 +1241,20 @@ fn list<'a>(payload: &BuiltinFunctionPayload<'a>) -> Value<'a> {
 
   match value {
     Value::List(items) => Value::List(items.clone()),
+    Value::Map(map) => {
+      Value::List(map.keys().cloned().map(Value::from).collect())
+    }
+    Value::Matrix(matrix) => Value::List(matrix.to_list()),
+    Value::Polynomial(polynomial) => Value::List(
+      polynomial
+        .coefficients()
+        .into_iter()
+        .map(Value::Number)
+        .collect(),
+    ),
+    Value::Range(range) => {
+      Value::List(range.iter().map(Value::Number).collect())
+    }
     Value::String(s) => Value::List(
       s.chars()
         .map(|c| Value::String(Cow::Owned(c.to_string())))
@@ -628,40 +1267,105 @@ fn list<'a>(payload: &BuiltinFunctionPayload<'a>) -> Value<'a> {
 fn ln<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let number = payload.arguments[0].number(payload.span)?;
 
-  if number.is_zero() || number.is_negative() {
-    return Err(Error::new(
-      payload.span,
-      "Cannot take logarithm of zero or negative number",
-    ));
+  if number.is_zero() {
+    return Err(Error::new(payload.span, "Cannot take logarithm of zero"));
   }
 
-  Ok(Value::Number(number.ln(payload.config)))
+  number
+    .ln(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
 }
 
 fn log10<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let number = payload.arguments[0].number(payload.span)?;
 
-  if number.is_zero() || number.is_negative() {
-    return Err(Error::new(
-      payload.span,
-      "Cannot take logarithm of zero or negative number",
-    ));
+  if number.is_zero() {
+    return Err(Error::new(payload.span, "Cannot take logarithm of zero"));
   }
 
-  Ok(Value::Number(number.log10(payload.config)))
+  number
+    .log10(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
 }
 
 fn log2<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let number = payload.arguments[0].number(payload.span)?;
 
-  if number.is_zero() || number.is_negative() {
+  if number.is_zero() {
+    return Err(Error::new(payload.span, "Cannot take logarithm of zero"));
+  }
+
+  number
+    .log2(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
+}
+
+fn lower<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0].number(payload.span)?.bounds().0,
+  ))
+}
+
+fn matrix<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Matrix(
+    payload.arguments[0].matrix(payload.span)?.into_owned(),
+  ))
+}
+
+fn next_prime<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let mut candidate = payload.arguments[0]
+    .integer(payload.span)?
+    .max(Integer::from(1));
+
+  loop {
+    candidate += 1;
+
+    if Factorization::is_prime(&candidate) {
+      return Ok(Value::Number(Number::from(candidate)));
+    }
+  }
+}
+
+fn poly<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  let coefficients = match payload.arguments.as_slice() {
+    [Value::List(items)] => items.as_slice(),
+    arguments => arguments,
+  };
+
+  Value::List(coefficients.to_vec())
+    .polynomial(payload.span)
+    .map(|polynomial| Value::Polynomial(polynomial.into_owned()))
+}
+
+fn polyval<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0]
+      .polynomial(payload.span)?
+      .evaluate(payload.arguments[1].number(payload.span)?, payload.config),
+  ))
+}
+
+fn popcount<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let Some(count) = payload.arguments[0].integer(payload.span)?.count_ones()
+  else {
     return Err(Error::new(
       payload.span,
-      "Cannot take logarithm of zero or negative number",
+      "Argument to `popcount` must be a non-negative integer",
     ));
-  }
+  };
 
-  Ok(Value::Number(number.log2(payload.config)))
+  Ok(Value::Number(Number::from(i64::from(count))))
 }
 
 fn print<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
@@ -753,24 +1457,41 @@ fn range<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
     ));
   }
 
-  let mut current = start;
-  let mut result = Vec::new();
+  Ok(Value::Range(Range::exclusive(start, end, step)))
+}
 
-  while if step > 0 {
-    current < end
-  } else {
-    current > end
-  } {
-    result.push(Value::Number(Number::from(current)));
+fn rank<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .matrix(payload.span)?
+    .rank(payload.config)
+    .map(|rank| Value::Number(Number::from(rank)))
+    .map_err(|error| error.with_span(payload.span))
+}
 
-    let Some(next) = current.checked_add(step) else {
-      break;
-    };
+fn re<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0].number(payload.span)?.re(),
+  ))
+}
 
-    current = next;
-  }
+fn remove<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let mut map = payload.arguments[0].map(payload.span)?.clone();
+
+  let key = payload.arguments[1].key(payload.span)?;
 
-  Ok(Value::List(result))
+  map.shift_remove(&key);
+
+  Ok(Value::Map(map))
+}
+
+fn roots<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .polynomial(payload.span)?
+    .roots(payload.config)
+    .map(|roots| Value::List(roots.into_iter().map(Value::Number).collect()))
+    .map_err(|error| error.with_span(payload.span))
 }
 
 fn sec<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
@@ -788,6 +1509,23 @@ fn sec<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
     .map_err(|error| error.with_span(payload.span))
 }
 
+fn set_bit<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let mut integer = payload.arguments[0].integer(payload.span)?;
+
+  let index = bit_index(payload, "set_bit")?;
+
+  let value = match payload.arguments.get(2) {
+    Some(value) => value.boolean(payload.span)?,
+    None => true,
+  };
+
+  integer.set_bit(index, value);
+
+  Ok(Value::Number(Number::from(integer)))
+}
+
 fn sin<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   Ok(Value::Number(
     payload.arguments[0]
@@ -804,6 +1542,14 @@ fn sinh<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   ))
 }
 
+fn solve<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  payload.arguments[0]
+    .matrix(payload.span)?
+    .solve(&*payload.arguments[1].matrix(payload.span)?, payload.config)
+    .map(Value::Matrix)
+    .map_err(|error| error.with_span(payload.span))
+}
+
 fn split<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   let string = payload.arguments[0].string(payload.span)?;
 
@@ -819,16 +1565,15 @@ fn split<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
 }
 
 fn sqrt<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
-  let number = payload.arguments[0].number(payload.span)?;
-
-  if number.is_negative() {
-    return Err(Error::new(
-      payload.span,
-      "Cannot take square root of negative number",
-    ));
-  }
+  let number = match &payload.arguments[0] {
+    Value::Fixed(fixed) => Number::from(*fixed),
+    value => value.number(payload.span)?.clone(),
+  };
 
-  Ok(Value::Number(number.sqrt(payload.config)))
+  number
+    .sqrt(payload.config)
+    .map(Value::Number)
+    .map_err(|error| error.with_span(payload.span))
 }
 
 fn sum<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
@@ -836,7 +1581,7 @@ fn sum<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
 
   let mut sum = Number::from(0_i64);
 
-  for value in list {
+  for value in list.iter() {
     sum = sum.add(value.number(payload.span)?, payload.config);
   }
 
@@ -859,6 +1604,96 @@ fn tanh<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
   ))
 }
 
+fn test_bit<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let integer = payload.arguments[0].integer(payload.span)?;
+
+  Ok(Value::Boolean(
+    integer.get_bit(bit_index(payload, "test_bit")?),
+  ))
+}
+
+fn totient<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  if payload.arguments[0].integer(payload.span)? <= 0 {
+    return Err(Error::new(
+      payload.span,
+      "Argument to `totient` must be a positive integer",
+    ));
+  }
+
+  Ok(Value::Number(Number::from(
+    factorization(payload, "totient")?.totient(),
+  )))
+}
+
+fn trailing_zeros<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let Some(zeros) = payload.arguments[0].integer(payload.span)?.find_one(0)
+  else {
+    return Err(Error::new(
+      payload.span,
+      "Argument to `trailing_zeros` must be a non-zero integer",
+    ));
+  };
+
+  Ok(Value::Number(Number::from(i64::from(zeros))))
+}
+
+fn transpose<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  Ok(Value::Matrix(
+    payload.arguments[0].matrix(payload.span)?.transpose(),
+  ))
+}
+
+fn u16<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::U16)
+}
+
+fn u32<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::U32)
+}
+
+fn u64<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::U64)
+}
+
+fn u8<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  fixed(payload, FixedType::U8)
+}
+
+fn upper<'a>(payload: &BuiltinFunctionPayload<'a>) -> Result<Value<'a>, Error> {
+  Ok(Value::Number(
+    payload.arguments[0].number(payload.span)?.bounds().1,
+  ))
+}
+
+fn values<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let map = payload.arguments[0].map(payload.span)?;
+
+  Ok(Value::List(map.values().cloned().collect()))
+}
+
+fn vector<'a>(
+  payload: &BuiltinFunctionPayload<'a>,
+) -> Result<Value<'a>, Error> {
+  let entries = match payload.arguments.as_slice() {
+    [Value::List(items)] => items.as_slice(),
+    arguments => arguments,
+  };
+
+  Value::List(entries.to_vec())
+    .matrix(payload.span)
+    .map(|matrix| Value::Matrix(matrix.into_owned()))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -3,16 +3,22 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct Config {
   pub digits: NonZeroUsize,
+  pub interval: bool,
+  pub overflow: Overflow,
   pub precision: u32,
   pub rounding_mode: Round,
+  pub strict: bool,
 }
 
 impl Default for Config {
   fn default() -> Self {
     Self {
       digits: NonZeroUsize::new(16).unwrap(),
+      interval: false,
+      overflow: Overflow::Wrapping,
       precision: 1024,
       rounding_mode: Round::Nearest,
+      strict: false,
     }
   }
 }
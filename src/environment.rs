@@ -4,9 +4,19 @@ use super::*;
 pub struct Environment<'src> {
   pub(crate) config: Config,
   pub(crate) frame: Rc<RefCell<Frame<'src>>>,
+  pub(crate) modules: Rc<RefCell<Modules<'src>>>,
+  pub(crate) path: Option<Rc<PathBuf>>,
 }
 
 impl<'src> Environment<'src> {
+  pub(crate) fn add_constant(
+    &self,
+    name: &str,
+    value: Value<'src>,
+  ) -> Result<(), Error> {
+    self.declare(name, value, true)
+  }
+
   pub fn add_function(&self, name: &str, function: Function<'src>) {
     let mut frame = self.frame.borrow_mut();
 
@@ -17,26 +27,22 @@ impl<'src> Environment<'src> {
         name.to_owned(),
         Symbol {
           function: Some(function),
-          value: None,
+          ..Symbol::default()
         },
       );
     }
   }
 
-  pub fn add_symbol(&self, name: &str, value: Value<'src>) {
-    let mut frame = self.frame.borrow_mut();
-
-    if let Some(symbol) = frame.symbols.get_mut(name) {
-      symbol.value = Some(value);
-    } else {
-      frame.symbols.insert(
-        name.to_owned(),
-        Symbol {
-          function: None,
-          value: Some(value),
-        },
-      );
-    }
+  /// # Errors
+  ///
+  /// Returns an error if the current scope already holds a constant named
+  /// `name`.
+  pub fn add_symbol(
+    &self,
+    name: &str,
+    value: Value<'src>,
+  ) -> Result<(), Error> {
+    self.declare(name, value, false)
   }
 
   fn assign_existing_symbol(
@@ -49,6 +55,10 @@ impl<'src> Environment<'src> {
 
       match frame.symbols.get_mut(name) {
         Some(symbol) if symbol.value.is_some() => {
+          if let Value::Function(_) = value {
+            symbol.function = None;
+          }
+
           symbol.value = Some(value);
           return Ok(());
         }
@@ -62,12 +72,86 @@ impl<'src> Environment<'src> {
     }
   }
 
-  pub(crate) fn assign_symbol(&self, name: &str, value: Value<'src>) {
+  pub(crate) fn assign_symbol(
+    &self,
+    name: &str,
+    value: Value<'src>,
+  ) -> Result<(), Error> {
+    if self.constant(name) {
+      return Err(Error::Message(format!(
+        "Cannot assign to constant `{name}`"
+      )));
+    }
+
     if let Err(value) = self.assign_existing_symbol(name, value) {
-      self.add_symbol(name, value);
+      if self.config.strict {
+        return Err(Error::Message(format!(
+          "Cannot assign to undeclared variable `{name}`, declare it with `let`"
+        )));
+      }
+
+      self.add_symbol(name, value)?;
+    }
+
+    Ok(())
+  }
+
+  fn constant(&self, name: &str) -> bool {
+    let frame = self.frame.borrow();
+
+    match frame.symbols.get(name) {
+      Some(symbol) if symbol.value.is_some() => symbol.constant,
+      _ => frame
+        .parent
+        .as_ref()
+        .is_some_and(|parent| parent.constant(name)),
     }
   }
 
+  fn declare(
+    &self,
+    name: &str,
+    value: Value<'src>,
+    constant: bool,
+  ) -> Result<(), Error> {
+    let mut frame = self.frame.borrow_mut();
+
+    let symbol = frame.symbols.entry(name.to_owned()).or_default();
+
+    if symbol.constant && symbol.value.is_some() {
+      return Err(Error::Message(format!(
+        "Cannot assign to constant `{name}`"
+      )));
+    }
+
+    if let Value::Function(_) = value {
+      symbol.function = None;
+    }
+
+    symbol.constant = constant;
+    symbol.value = Some(value);
+
+    Ok(())
+  }
+
+  pub(crate) fn exports(&self) -> IndexMap<Key<'src>, Value<'src>> {
+    let frame = self.frame.borrow();
+
+    let mut names = frame.symbols.keys().collect::<Vec<_>>();
+
+    names.sort();
+
+    names
+      .into_iter()
+      .filter_map(|name| {
+        Some((
+          Key::String(Cow::Owned(name.clone())),
+          self.local_symbol(name)?,
+        ))
+      })
+      .collect()
+  }
+
   pub(crate) fn function(
     &self,
     name: &str,
@@ -107,17 +191,34 @@ impl<'src> Environment<'src> {
       .or_else(|| symbol.function.clone().map(Value::Function))
   }
 
+  pub(crate) fn module(&self, path: &Path) -> Self {
+    let mut builtins = Self::new(self.config);
+
+    builtins.modules = self.modules.clone();
+    builtins.path = Some(Rc::new(path.to_owned()));
+
+    Self::with_parent(builtins)
+  }
+
   #[must_use]
   pub fn new(config: Config) -> Self {
     let environment = Self {
       config,
       frame: Rc::new(RefCell::new(Frame::default())),
+      modules: Rc::default(),
+      path: None,
     };
 
     for builtin in BUILTINS {
       match builtin {
         Builtin::Constant { value, .. } => {
-          environment.add_symbol(builtin.name(), Value::Number(value(config)));
+          environment.frame.borrow_mut().symbols.insert(
+            builtin.name().to_owned(),
+            Symbol {
+              value: Some(Value::Number(value(config))),
+              ..Symbol::default()
+            },
+          );
         }
         Builtin::Function {
           arity, function, ..
@@ -152,12 +253,26 @@ impl<'src> Environment<'src> {
   pub(crate) fn with_parent(parent: Environment<'src>) -> Self {
     Self {
       config: parent.config,
+      modules: parent.modules.clone(),
+      path: parent.path.clone(),
       frame: Rc::new(RefCell::new(Frame {
         parent: Some(parent),
         symbols: HashMap::new(),
       })),
     }
   }
+
+  #[must_use]
+  pub fn with_path(self, path: &Path) -> Self {
+    if let Ok(path) = fs::canonicalize(path) {
+      self.modules.borrow_mut().loading.push(path);
+    }
+
+    Self {
+      path: Some(Rc::new(path.to_owned())),
+      ..self
+    }
+  }
 }
 
 impl fmt::Debug for Environment<'_> {
@@ -6,6 +6,8 @@ pub enum Error {
   DivisionByZero,
   #[error("exit requested with code {code}")]
   Exit { code: i32, span: Span },
+  #[error("exponent too large")]
+  ExponentTooLarge,
   #[error("invalid decimal")]
   InvalidDecimal,
   #[error("{0}")]
@@ -14,18 +16,37 @@ pub enum Error {
   ModuloByZero,
   #[error("{error}")]
   Spanned { error: Box<Self>, span: Span },
+  #[error("{message}")]
+  Thrown { message: String, span: Span },
   #[error("zero cannot be raised to a negative power")]
   ZeroToNegativePower,
 }
 
 impl Error {
+  #[must_use]
+  pub fn kind(&self) -> &'static str {
+    match self {
+      Self::DivisionByZero => "division_by_zero",
+      Self::Exit { .. } => "exit",
+      Self::ExponentTooLarge => "exponent_too_large",
+      Self::InvalidDecimal => "invalid_decimal",
+      Self::Message(_) => "error",
+      Self::ModuloByZero => "modulo_by_zero",
+      Self::Spanned { error, .. } => error.kind(),
+      Self::Thrown { .. } => "thrown",
+      Self::ZeroToNegativePower => "zero_to_negative_power",
+    }
+  }
+
   pub fn new(span: Span, message: impl Into<String>) -> Self {
     Self::Message(message.into()).with_span(span)
   }
 
   #[must_use]
-  pub fn report<'a>(&self, id: &'a str) -> Report<'a, (&'a str, Range<usize>)> {
-    let span_range = self.span().into_range();
+  pub fn report(&self) -> Report<'static, (SourceId, std::ops::Range<usize>)> {
+    let span = self.span();
+
+    let (id, span_range) = (span.context, span.into_range());
 
     let mut report = Report::build(
       ReportKind::Custom("error", Color::Red),
@@ -46,8 +67,10 @@ impl Error {
   #[must_use]
   pub fn span(&self) -> Span {
     match self {
-      Self::Exit { span, .. } | Self::Spanned { span, .. } => *span,
-      _ => Span::from(0..0),
+      Self::Exit { span, .. }
+      | Self::Spanned { span, .. }
+      | Self::Thrown { span, .. } => *span,
+      _ => SourceId::default().span(0..0),
     }
   }
 
@@ -56,6 +79,7 @@ impl Error {
     match self {
       Self::Exit { code, .. } => Self::Exit { code, span },
       Self::Spanned { error, .. } => Self::Spanned { error, span },
+      Self::Thrown { message, .. } => Self::Thrown { message, span },
       error => Self::Spanned {
         error: Box::new(error),
         span,
@@ -3,38 +3,114 @@ use super::*;
 pub struct Evaluator<'a> {
   pub(crate) context: Context,
   pub(crate) environment: Environment<'a>,
+  pub(crate) sources: Rc<RefCell<Sources>>,
+  pub(crate) thrown: Rc<RefCell<Option<Value<'a>>>>,
 }
 
 impl<'a> Evaluator<'a> {
+  pub fn add_source(&self, name: impl Into<String>, text: &str) -> SourceId {
+    self.sources.borrow_mut().add(name, text)
+  }
+
+  fn arithmetic(
+    &self,
+    op: &BinaryOp,
+    lhs: Spanned<Value<'a>>,
+    rhs: Spanned<Value<'a>>,
+  ) -> Result<Value<'a>, Error> {
+    let config = self.environment.config;
+
+    let ((lhs, lhs_span), (rhs, rhs_span)) = (lhs, rhs);
+
+    match (&lhs, &rhs) {
+      (Value::String(_), _) | (_, Value::String(_)) => {}
+      (Value::Fixed(_), _) | (_, Value::Fixed(_)) => {
+        return Self::fixed(op, (lhs, lhs_span), (rhs, rhs_span));
+      }
+      (Value::Matrix(_), _) | (_, Value::Matrix(_)) => {
+        return Self::matrix(op, (lhs, lhs_span), (rhs, rhs_span), config);
+      }
+      (Value::Polynomial(_), _) | (_, Value::Polynomial(_)) => {
+        return Self::polynomial(op, (lhs, lhs_span), (rhs, rhs_span), config);
+      }
+      _ => {}
+    }
+
+    if let BinaryOp::Add = op {
+      match (lhs, rhs) {
+        (Value::String(a), Value::String(b)) => {
+          let mut result = a.into_owned();
+          result.push_str(&b);
+          return Ok(Value::String(Cow::Owned(result)));
+        }
+        (Value::String(a), rhs) => {
+          let mut result = a.into_owned();
+          result.push_str(&rhs.display(config));
+          return Ok(Value::String(Cow::Owned(result)));
+        }
+        (lhs, Value::String(b)) => {
+          let mut result = lhs.display(config);
+          result.push_str(&b);
+          return Ok(Value::String(Cow::Owned(result)));
+        }
+        (Value::List(mut a), Value::List(b)) => {
+          a.extend(b);
+          return Ok(Value::List(a));
+        }
+        (lhs, rhs) => {
+          return Ok(Value::Number(
+            lhs.number(lhs_span)?.add(rhs.number(rhs_span)?, config),
+          ));
+        }
+      }
+    }
+
+    if let BinaryOp::BitwiseAnd
+    | BinaryOp::BitwiseOr
+    | BinaryOp::BitwiseXor
+    | BinaryOp::ShiftLeft
+    | BinaryOp::ShiftRight = op
+    {
+      return Self::bitwise(op, (lhs, lhs_span), (rhs, rhs_span));
+    }
+
+    let (lhs, rhs) = (lhs.number(lhs_span)?, rhs.number(rhs_span)?);
+
+    match op {
+      BinaryOp::Divide => lhs.div(rhs, config),
+      BinaryOp::Modulo => lhs.rem(rhs, config),
+      BinaryOp::Hadamard | BinaryOp::Multiply => Ok(lhs.mul(rhs, config)),
+      BinaryOp::Power => lhs.pow(rhs, config),
+      BinaryOp::Subtract => Ok(lhs.sub(rhs, config)),
+      _ => unreachable!(),
+    }
+    .map(Value::Number)
+    .map_err(|error| error.with_span(rhs_span))
+  }
+
   fn assign(
     &mut self,
     target: &Spanned<AssignmentTarget>,
     value: Value<'a>,
   ) -> Result<(), Error> {
     match &target.0 {
-      AssignmentTarget::Identifier(name) => {
-        self.environment.assign_symbol(name, value);
-        Ok(())
-      }
-      AssignmentTarget::ListAccess(_, _) => {
-        let (name, name_span) = target.0.root(target.1);
-
-        let indices = target.0.indices();
-
-        let Some(root) = self.environment.resolve_symbol(name) else {
-          return Err(Error::new(
-            name_span,
-            format!("Undefined variable `{name}`"),
-          ));
-        };
-
-        let root =
-          self.assign_indices(name, root, &indices, value, target.1)?;
-
-        self.environment.assign_symbol(name, root);
+      AssignmentTarget::Identifier(name) => self
+        .environment
+        .assign_symbol(name, value)
+        .map_err(|error| error.with_span(target.1)),
+      AssignmentTarget::List(targets) => {
+        for (target, value) in targets
+          .iter()
+          .zip(Self::destructure(targets, value, target.1)?)
+        {
+          self.assign(target, value)?;
+        }
 
         Ok(())
       }
+      AssignmentTarget::ListAccess(_, _) | AssignmentTarget::Slice(_, _) => {
+        self.update(target, |_, _| Ok(value))
+      }
     }
   }
 
@@ -43,41 +119,203 @@ impl<'a> Evaluator<'a> {
     name: &str,
     value: Value<'a>,
     indices: &[&Spanned<Expression>],
-    assigned: Value<'a>,
+    updated: impl FnOnce(
+      &Self,
+      Result<Value<'a>, Error>,
+    ) -> Result<Value<'a>, Error>,
     span: Span,
   ) -> Result<Value<'a>, Error> {
     let Some((index, rest)) = indices.split_first() else {
-      return Ok(assigned);
+      return updated(self, Ok(value));
     };
 
-    let mut list = match value {
-      Value::List(items) => items,
-      other => {
+    match value {
+      Value::List(mut list) => {
+        let index =
+          self.evaluate_list_index(index, list.len(), "list", span)?;
+
+        let value = std::mem::replace(&mut list[index], Value::Null);
+
+        list[index] = self.assign_indices(name, value, rest, updated, span)?;
+
+        Ok(Value::List(list))
+      }
+      Value::Map(mut map) => {
+        let key = self.evaluate_expression(index)?.key(index.1)?;
+
+        let value = map
+          .get_mut(&key)
+          .map(|value| std::mem::replace(value, Value::Null))
+          .ok_or_else(|| {
+            Error::new(index.1, format!("Key {key} not found in map"))
+          });
+
+        let value = if rest.is_empty() {
+          updated(self, value)?
+        } else {
+          self.assign_indices(name, value?, rest, updated, span)?
+        };
+
+        map.insert(key, value);
+
+        Ok(Value::Map(map))
+      }
+      other => Err(Error::new(
+        index.1,
+        format!("'{}' is not a list (found {})", name, other.type_name()),
+      )),
+    }
+  }
+
+  fn bind(
+    &mut self,
+    target: &Spanned<AssignmentTarget>,
+    value: Value<'a>,
+    constant: bool,
+  ) -> Result<(), Error> {
+    match &target.0 {
+      AssignmentTarget::Identifier(name) if constant => self
+        .environment
+        .add_constant(name, value)
+        .map_err(|error| error.with_span(target.1)),
+      AssignmentTarget::Identifier(name) => self
+        .environment
+        .add_symbol(name, value)
+        .map_err(|error| error.with_span(target.1)),
+      AssignmentTarget::List(targets) => {
+        for (target, value) in targets
+          .iter()
+          .zip(Self::destructure(targets, value, target.1)?)
+        {
+          self.bind(target, value, constant)?;
+        }
+
+        Ok(())
+      }
+      AssignmentTarget::ListAccess(_, _) | AssignmentTarget::Slice(_, _) => {
+        self.assign(target, value)
+      }
+    }
+  }
+
+  fn bitwise(
+    op: &BinaryOp,
+    lhs: Spanned<Value<'a>>,
+    rhs: Spanned<Value<'a>>,
+  ) -> Result<Value<'a>, Error> {
+    let ((lhs, lhs_span), (rhs, rhs_span)) = (lhs, rhs);
+
+    let (lhs, rhs) = (lhs.integer(lhs_span)?, rhs.integer(rhs_span)?);
+
+    let shift = || {
+      rhs.to_u32().ok_or_else(|| {
+        Error::new(
+          rhs_span,
+          format!("Shift amount must be between 0 and {}", u32::MAX),
+        )
+      })
+    };
+
+    let result = match op {
+      BinaryOp::BitwiseAnd => lhs & rhs,
+      BinaryOp::BitwiseOr => lhs | rhs,
+      BinaryOp::BitwiseXor => lhs ^ rhs,
+      BinaryOp::ShiftLeft => lhs << shift()?,
+      BinaryOp::ShiftRight => lhs >> shift()?,
+      _ => unreachable!(),
+    };
+
+    Ok(Value::Number(Number::from(result)))
+  }
+
+  fn compare(
+    op: &BinaryOp,
+    lhs: &Value<'a>,
+    rhs: &Value<'a>,
+    span: Span,
+  ) -> Result<bool, Error> {
+    for value in [lhs, rhs] {
+      if let Value::Number(number) = value
+        && number.is_complex()
+      {
         return Err(Error::new(
-          index.1,
-          format!("'{}' is not a list (found {})", name, other.type_name()),
+          span,
+          format!("Cannot order complex number {number} with '{op}'"),
         ));
       }
-    };
+    }
 
-    let index = self.evaluate_list_index(index)?;
+    let ordering = match (lhs, rhs) {
+      (Value::Fixed(a), Value::Fixed(b)) => {
+        a.to_integer().partial_cmp(&b.to_integer())
+      }
+      (Value::Fixed(a), Value::Number(b)) => Number::from(*a).partial_cmp(b),
+      (Value::Number(a), Value::Fixed(b)) => a.partial_cmp(&Number::from(*b)),
+      (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+      (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+      _ => {
+        return Err(Error::new(
+          span,
+          format!(
+            "Cannot compare {} and {} with '{}'",
+            lhs.type_name(),
+            rhs.type_name(),
+            op
+          ),
+        ));
+      }
+    };
 
-    if index >= list.len() {
+    if ordering.is_none()
+      && let (Value::Number(lhs @ Number::Interval(_)), Value::Number(rhs))
+      | (Value::Number(lhs), Value::Number(rhs @ Number::Interval(_))) =
+        (lhs, rhs)
+    {
       return Err(Error::new(
         span,
         format!(
-          "Index {} out of bounds for list of length {}",
-          index,
-          list.len()
+          "Cannot order overlapping intervals {lhs} and {rhs} with '{op}'"
         ),
       ));
     }
 
-    let value = std::mem::replace(&mut list[index], Value::Null);
+    Ok(match op {
+      BinaryOp::LessThan => ordering == Some(Ordering::Less),
+      BinaryOp::LessThanEqual => {
+        matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+      }
+      BinaryOp::GreaterThan => ordering == Some(Ordering::Greater),
+      BinaryOp::GreaterThanEqual => {
+        matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+      }
+      _ => unreachable!(),
+    })
+  }
 
-    list[index] = self.assign_indices(name, value, rest, assigned, span)?;
+  fn destructure(
+    targets: &[Spanned<AssignmentTarget>],
+    value: Value<'a>,
+    span: Span,
+  ) -> Result<Vec<Value<'a>>, Error> {
+    let expected = match targets.len() {
+      1 => "1 target".into(),
+      len => format!("{len} targets"),
+    };
 
-    Ok(Value::List(list))
+    match value {
+      Value::List(values) if values.len() == targets.len() => Ok(values),
+      Value::List(values) => Err(Error::new(
+        span,
+        format!(
+          "Cannot destructure list of length {} into {expected}",
+          values.len()
+        ),
+      )),
+      value => Err(Error::new(
+        span,
+        format!("Cannot destructure {} into {expected}", value.type_name()),
+      )),
+    }
   }
 
   pub(crate) fn enter_function<T>(
@@ -125,66 +363,73 @@ impl<'a> Evaluator<'a> {
     }
   }
 
-  fn evaluate_expression(
+  fn evaluate_comparison(
+    &mut self,
+    first: &Spanned<Expression>,
+    rest: &[(BinaryOp, Spanned<Expression>)],
+    span: Span,
+  ) -> Result<Value<'a>, Error> {
+    let mut lhs = self.evaluate_expression(first)?;
+
+    for (op, operand) in rest {
+      let rhs = self.evaluate_expression(operand)?;
+
+      if !Self::compare(op, &lhs, &rhs, span)? {
+        return Ok(Value::Boolean(false));
+      }
+
+      lhs = rhs;
+    }
+
+    Ok(Value::Boolean(true))
+  }
+
+  pub(crate) fn evaluate_expression(
     &mut self,
     ast: &Spanned<Expression>,
   ) -> Result<Value<'a>, Error> {
     let (node, span) = ast;
 
     match node {
-      Expression::BinaryOp(BinaryOp::Add, lhs, rhs) => {
-        let (lhs_val, rhs_val) = (
-          self.evaluate_expression(lhs)?,
-          self.evaluate_expression(rhs)?,
-        );
+      Expression::BinaryOp(
+        op @ (BinaryOp::Add
+        | BinaryOp::BitwiseAnd
+        | BinaryOp::BitwiseOr
+        | BinaryOp::BitwiseXor
+        | BinaryOp::Divide
+        | BinaryOp::Hadamard
+        | BinaryOp::Modulo
+        | BinaryOp::Multiply
+        | BinaryOp::Power
+        | BinaryOp::ShiftLeft
+        | BinaryOp::ShiftRight
+        | BinaryOp::Subtract),
+        lhs,
+        rhs,
+      ) => {
+        let lhs = (self.evaluate_expression(lhs)?, lhs.1);
+        let rhs = (self.evaluate_expression(rhs)?, rhs.1);
 
-        match (lhs_val, rhs_val) {
-          (Value::Number(a), Value::Number(b)) => {
-            Ok(Value::Number(a.add(&b, self.environment.config)))
-          }
-          (Value::String(a), Value::String(b)) => {
-            let mut result = a.into_owned();
-            result.push_str(&b);
-            Ok(Value::String(Cow::Owned(result)))
-          }
-          (Value::String(a), rhs) => {
-            let mut result = a.into_owned();
-            result.push_str(&rhs.display(self.environment.config));
-            Ok(Value::String(Cow::Owned(result)))
-          }
-          (lhs, Value::String(b)) => {
-            let mut result = lhs.display(self.environment.config);
-            result.push_str(&b);
-            Ok(Value::String(Cow::Owned(result)))
-          }
-          (Value::List(mut a), Value::List(b)) => {
-            a.extend(b);
-            Ok(Value::List(a))
-          }
-          (lhs_value, rhs_value) => Ok(Value::Number(
-            lhs_value
-              .number(lhs.1)?
-              .add(rhs_value.number(rhs.1)?, self.environment.config),
-          )),
-        }
+        self.arithmetic(op, lhs, rhs)
       }
-      Expression::BinaryOp(BinaryOp::Divide, lhs, rhs) => {
-        let (lhs_val, rhs_val) = (
-          self.evaluate_expression(lhs)?,
-          self.evaluate_expression(rhs)?,
-        );
-
-        let (lhs_num, rhs_num) =
-          (lhs_val.number(lhs.1)?, rhs_val.number(rhs.1)?);
-
-        lhs_num
-          .div(rhs_num, self.environment.config)
-          .map(Value::Number)
-          .map_err(|error| error.with_span(rhs.1))
+      Expression::BinaryOp(BinaryOp::Coalesce, lhs, rhs) => {
+        match self.evaluate_expression(lhs)? {
+          Value::Null => self.evaluate_expression(rhs),
+          value => Ok(value),
+        }
       }
       Expression::BinaryOp(BinaryOp::Equal, lhs, rhs) => Ok(Value::Boolean(
         self.evaluate_expression(lhs)? == self.evaluate_expression(rhs)?,
       )),
+      Expression::BinaryOp(BinaryOp::In, lhs, rhs) => {
+        let needle = self.evaluate_expression(lhs)?;
+
+        Ok(Value::Boolean(
+          self
+            .evaluate_expression(rhs)?
+            .contains(&needle, lhs.1, rhs.1)?,
+        ))
+      }
       Expression::BinaryOp(
         op @ (BinaryOp::LessThan
         | BinaryOp::LessThanEqual
@@ -193,40 +438,12 @@ impl<'a> Evaluator<'a> {
         lhs,
         rhs,
       ) => {
-        let (lhs_val, rhs_val) = (
+        let (lhs, rhs) = (
           self.evaluate_expression(lhs)?,
           self.evaluate_expression(rhs)?,
         );
 
-        match (&lhs_val, &rhs_val) {
-          (Value::Number(a), Value::Number(b)) => {
-            Ok(Value::Boolean(match op {
-              BinaryOp::LessThan => a < b,
-              BinaryOp::LessThanEqual => a <= b,
-              BinaryOp::GreaterThan => a > b,
-              BinaryOp::GreaterThanEqual => a >= b,
-              _ => unreachable!(),
-            }))
-          }
-          (Value::String(a), Value::String(b)) => {
-            Ok(Value::Boolean(match op {
-              BinaryOp::LessThan => a < b,
-              BinaryOp::LessThanEqual => a <= b,
-              BinaryOp::GreaterThan => a > b,
-              BinaryOp::GreaterThanEqual => a >= b,
-              _ => unreachable!(),
-            }))
-          }
-          _ => Err(Error::new(
-            *span,
-            format!(
-              "Cannot compare {} and {} with '{}'",
-              lhs_val.type_name(),
-              rhs_val.type_name(),
-              op
-            ),
-          )),
-        }
+        Ok(Value::Boolean(Self::compare(op, &lhs, &rhs, *span)?))
       }
       Expression::BinaryOp(BinaryOp::LogicalAnd, lhs, rhs) => {
         Ok(Value::Boolean(
@@ -240,50 +457,35 @@ impl<'a> Evaluator<'a> {
             || self.evaluate_expression(rhs)?.boolean(rhs.1)?,
         ))
       }
-      Expression::BinaryOp(BinaryOp::Modulo, lhs, rhs) => {
-        let (lhs_val, rhs_val) = (
-          self.evaluate_expression(lhs)?,
-          self.evaluate_expression(rhs)?,
-        );
-
-        let (lhs_num, rhs_num) =
-          (lhs_val.number(lhs.1)?, rhs_val.number(rhs.1)?);
-
-        lhs_num
-          .rem(rhs_num, self.environment.config)
-          .map(Value::Number)
-          .map_err(|error| error.with_span(rhs.1))
-      }
-      Expression::BinaryOp(BinaryOp::Multiply, lhs, rhs) => Ok(Value::Number(
-        self.evaluate_expression(lhs)?.number(lhs.1)?.mul(
-          self.evaluate_expression(rhs)?.number(rhs.1)?,
-          self.environment.config,
-        ),
-      )),
       Expression::BinaryOp(BinaryOp::NotEqual, lhs, rhs) => Ok(Value::Boolean(
         self.evaluate_expression(lhs)? != self.evaluate_expression(rhs)?,
       )),
-      Expression::BinaryOp(BinaryOp::Power, lhs, rhs) => {
-        let (lhs_val, rhs_val) = (
-          self.evaluate_expression(lhs)?,
-          self.evaluate_expression(rhs)?,
+      Expression::BinaryOp(
+        op @ (BinaryOp::Range | BinaryOp::RangeInclusive),
+        lhs,
+        rhs,
+      ) => {
+        let (start, end) = (
+          self.evaluate_range_bound(lhs)?,
+          self.evaluate_range_bound(rhs)?,
         );
 
-        let (lhs_num, rhs_num) =
-          (lhs_val.number(lhs.1)?, rhs_val.number(rhs.1)?);
-
-        lhs_num
-          .pow(rhs_num, self.environment.config)
-          .map(Value::Number)
-          .map_err(|error| error.with_span(rhs.1))
+        Ok(Value::Range(match op {
+          BinaryOp::RangeInclusive => Range::inclusive(start, end),
+          _ => Range::exclusive(start, end, 1),
+        }))
       }
-      Expression::BinaryOp(BinaryOp::Subtract, lhs, rhs) => Ok(Value::Number(
-        self.evaluate_expression(lhs)?.number(lhs.1)?.sub(
-          self.evaluate_expression(rhs)?.number(rhs.1)?,
-          self.environment.config,
-        ),
-      )),
       Expression::Boolean(boolean) => Ok(Value::Boolean(*boolean)),
+      Expression::Comparison(first, rest) => {
+        self.evaluate_comparison(first, rest, *span)
+      }
+      Expression::Conditional(condition, then_branch, else_branch) => {
+        if self.evaluate_expression(condition)?.boolean(condition.1)? {
+          self.evaluate_expression(then_branch)
+        } else {
+          self.evaluate_expression(else_branch)
+        }
+      }
       Expression::Function(parameters, body) => {
         Ok(Value::Function(Function::UserDefined {
           body: body.clone(),
@@ -293,7 +495,7 @@ impl<'a> Evaluator<'a> {
           parameters: parameters.clone(),
         }))
       }
-      Expression::FunctionCall(function, arguments) => {
+      Expression::FunctionCall(function, arguments, named) => {
         let function = match &function.0 {
           Expression::Identifier(name) => {
             self.environment.function(name, *span)
@@ -307,7 +509,14 @@ impl<'a> Evaluator<'a> {
           },
         }?;
 
-        function.check_arity(arguments.len(), *span)?;
+        function.check_arguments(
+          arguments.len(),
+          &named
+            .iter()
+            .map(|((name, name_span), _)| (name.as_str(), *name_span))
+            .collect::<Vec<_>>(),
+          *span,
+        )?;
 
         let mut evaluated_arguments = Vec::with_capacity(arguments.len());
 
@@ -315,7 +524,14 @@ impl<'a> Evaluator<'a> {
           evaluated_arguments.push(self.evaluate_expression(argument)?);
         }
 
-        function.call(evaluated_arguments, self.environment.config, *span)
+        let mut evaluated_named = Vec::with_capacity(named.len());
+
+        for ((name, _), argument) in named {
+          evaluated_named
+            .push((name.as_str(), self.evaluate_expression(argument)?));
+        }
+
+        function.call(self, evaluated_arguments, evaluated_named, *span)
       }
       Expression::Identifier(name) => {
         match self.environment.resolve_symbol(name) {
@@ -325,6 +541,19 @@ impl<'a> Evaluator<'a> {
           }
         }
       }
+      Expression::Interpolation(parts) => {
+        let mut result = String::new();
+
+        for part in parts {
+          result.push_str(
+            &self
+              .evaluate_expression(part)?
+              .display(self.environment.config),
+          );
+        }
+
+        Ok(Value::String(Cow::Owned(result)))
+      }
       Expression::List(list) => {
         let mut evaluated_list = Vec::with_capacity(list.len());
 
@@ -335,50 +564,304 @@ impl<'a> Evaluator<'a> {
         Ok(Value::List(evaluated_list))
       }
       Expression::ListAccess(list, index) => {
-        let list = self.evaluate_expression(list)?.into_list(list.1)?;
-
-        let index = self.evaluate_list_index(index)?;
-
-        if index >= list.len() {
-          return Err(Error::new(
-            *span,
-            format!(
-              "Index {} out of bounds for list of length {}",
-              index,
-              list.len()
-            ),
-          ));
+        self.evaluate_list_access(list, index, *span)
+      }
+      Expression::Map(entries) => {
+        let mut map = IndexMap::with_capacity(entries.len());
+
+        for (key, value) in entries {
+          map.insert(
+            self.evaluate_expression(key)?.key(key.1)?,
+            self.evaluate_expression(value)?,
+          );
         }
 
-        Ok(list.into_iter().nth(index).unwrap())
+        Ok(Value::Map(map))
       }
+      Expression::Match(subject, arms) => self.evaluate_match(subject, arms),
+      Expression::Member(base, name) => self.evaluate_member(base, name),
       Expression::Null => Ok(Value::Null),
       Expression::Number(number) => Ok(Value::Number(number.clone())),
+      Expression::Slice(list, slice) => self.evaluate_slice(list, slice),
       Expression::String(string) => {
         Ok(Value::String(Cow::Owned(string.clone())))
       }
-      Expression::UnaryOp(UnaryOp::Negate, rhs) => Ok(Value::Number(
-        self.evaluate_expression(rhs)?.number(rhs.1)?.neg(),
-      )),
+      Expression::UnaryOp(UnaryOp::Abs, rhs) => {
+        match self.evaluate_expression(rhs)? {
+          Value::Fixed(fixed) if fixed.to_integer().is_negative() => fixed
+            .negate()
+            .map(Value::Fixed)
+            .map_err(|error| error.with_span(*span)),
+          value @ Value::Fixed(_) => Ok(value),
+          value => Ok(Value::Number(
+            value.number(rhs.1)?.abs(self.environment.config),
+          )),
+        }
+      }
+      Expression::UnaryOp(UnaryOp::BitwiseNot, rhs) => {
+        match self.evaluate_expression(rhs)? {
+          Value::Fixed(fixed) => Ok(Value::Fixed(!fixed)),
+          value => Ok(Value::Number(Number::from(!value.integer(rhs.1)?))),
+        }
+      }
+      Expression::UnaryOp(UnaryOp::Factorial, rhs) => {
+        let number = match self.evaluate_expression(rhs)? {
+          Value::Fixed(fixed) => Number::from(fixed),
+          value => value.number(rhs.1)?.clone(),
+        };
+
+        number
+          .factorial(self.environment.config)
+          .map(Value::Number)
+          .map_err(|error| error.with_span(*span))
+      }
+      Expression::UnaryOp(UnaryOp::Negate, rhs) => {
+        match self.evaluate_expression(rhs)? {
+          Value::Fixed(fixed) => fixed
+            .negate()
+            .map(Value::Fixed)
+            .map_err(|error| error.with_span(*span)),
+          Value::Matrix(matrix) => Ok(Value::Matrix(matrix.neg())),
+          Value::Polynomial(polynomial) => {
+            Ok(Value::Polynomial(polynomial.neg()))
+          }
+          value => Ok(Value::Number(value.number(rhs.1)?.neg())),
+        }
+      }
       Expression::UnaryOp(UnaryOp::Not, rhs) => Ok(Value::Boolean(
         !self.evaluate_expression(rhs)?.boolean(rhs.1)?,
       )),
+      Expression::UnaryOp(UnaryOp::SquareRoot, rhs) => {
+        let number = match self.evaluate_expression(rhs)? {
+          Value::Fixed(fixed) => Number::from(fixed),
+          value => value.number(rhs.1)?.clone(),
+        };
+
+        number
+          .sqrt(self.environment.config)
+          .map(Value::Number)
+          .map_err(|error| error.with_span(*span))
+      }
     }
   }
 
+  fn evaluate_list_access(
+    &mut self,
+    list: &Spanned<Expression>,
+    index: &Spanned<Expression>,
+    span: Span,
+  ) -> Result<Value<'a>, Error> {
+    let list = match self.evaluate_expression(list)? {
+      Value::Map(map) => {
+        let key = self.evaluate_expression(index)?.key(index.1)?;
+
+        return map.get(&key).cloned().ok_or_else(|| {
+          Error::new(index.1, format!("Key {key} not found in map"))
+        });
+      }
+      Value::Range(range) => {
+        let index =
+          self.evaluate_list_index(index, range.len(), "range", span)?;
+
+        return Ok(Value::Number(range.get(index).unwrap()));
+      }
+      Value::String(string) => {
+        let index = self.evaluate_list_index(
+          index,
+          string.chars().count(),
+          "string",
+          span,
+        )?;
+
+        return Ok(Value::String(Cow::Owned(
+          string.chars().nth(index).unwrap().to_string(),
+        )));
+      }
+      value => value.into_list(list.1)?,
+    };
+
+    let index = self.evaluate_list_index(index, list.len(), "list", span)?;
+
+    Ok(list.into_iter().nth(index).unwrap())
+  }
+
   fn evaluate_list_index(
     &mut self,
     index: &Spanned<Expression>,
+    len: usize,
+    kind: &str,
+    span: Span,
   ) -> Result<usize, Error> {
-    self
+    let index = self
       .evaluate_expression(index)?
       .number(index.1)?
-      .to_non_negative_usize()
+      .to_integer()
+      .ok_or_else(|| Error::new(index.1, "Index must be a finite integer"))?;
+
+    let position = if index.is_negative() {
+      (&index + len).complete()
+    } else {
+      index.clone()
+    };
+
+    position
+      .to_usize()
+      .filter(|position| *position < len)
       .ok_or_else(|| {
-        Error::new(index.1, "List index must be a non-negative finite number")
+        Error::new(
+          span,
+          format!("Index {index} out of bounds for {kind} of length {len}"),
+        )
       })
   }
 
+  fn evaluate_match(
+    &mut self,
+    subject: &Spanned<Expression>,
+    arms: &[MatchArm],
+  ) -> Result<Value<'a>, Error> {
+    let value = self.evaluate_expression(subject)?;
+
+    for arm in arms {
+      let mut bindings = Vec::new();
+
+      if !self.match_pattern(&arm.pattern, &value, &mut bindings)? {
+        continue;
+      }
+
+      let environment = Environment::with_parent(self.environment.clone());
+
+      for (name, value) in bindings {
+        environment
+          .add_symbol(name, value)
+          .map_err(|error| error.with_span(arm.pattern.1))?;
+      }
+
+      let result = self.with_environment(environment, |evaluator| {
+        if let Some(guard) = &arm.guard
+          && !evaluator.evaluate_expression(guard)?.boolean(guard.1)?
+        {
+          return Ok(None);
+        }
+
+        evaluator.evaluate_expression(&arm.body).map(Some)
+      })?;
+
+      if let Some(result) = result {
+        return Ok(result);
+      }
+    }
+
+    Err(Error::new(
+      subject.1,
+      format!("No match arm matches '{value}'"),
+    ))
+  }
+
+  fn evaluate_member(
+    &mut self,
+    base: &Spanned<Expression>,
+    name: &Spanned<String>,
+  ) -> Result<Value<'a>, Error> {
+    match self.evaluate_expression(base)? {
+      Value::Error(exception) => exception.get(&name.0).ok_or_else(|| {
+        Error::new(name.1, format!("Cannot access `{}` on error", name.0))
+      }),
+      Value::Map(mut map) => {
+        let key = Key::String(Cow::Owned(name.0.clone()));
+
+        map.swap_remove(&key).ok_or_else(|| {
+          Error::new(name.1, format!("Key {key} not found in map"))
+        })
+      }
+      value => Err(Error::new(
+        name.1,
+        format!("Cannot access `{}` on {}", name.0, value.type_name()),
+      )),
+    }
+  }
+
+  fn evaluate_range_bound(
+    &mut self,
+    bound: &Spanned<Expression>,
+  ) -> Result<i64, Error> {
+    self
+      .evaluate_expression(bound)?
+      .number(bound.1)?
+      .to_i64()
+      .ok_or_else(|| {
+        Error::new(bound.1, "Range bounds must be finite integers")
+      })
+  }
+
+  fn evaluate_slice(
+    &mut self,
+    list: &Spanned<Expression>,
+    slice: &Slice,
+  ) -> Result<Value<'a>, Error> {
+    let value = self.evaluate_expression(list)?;
+    let bounds = self.evaluate_slice_bounds(slice)?;
+
+    match value {
+      Value::List(list) => Ok(Value::List(
+        Self::slice_positions(list.len(), bounds)
+          .map(|position| list[position].clone())
+          .collect(),
+      )),
+      Value::Range(range) => Ok(Value::List(
+        Self::slice_positions(range.len(), bounds)
+          .map_while(|position| range.get(position).map(Value::Number))
+          .collect(),
+      )),
+      Value::String(string) => {
+        let chars = string.chars().collect::<Vec<_>>();
+
+        Ok(Value::String(Cow::Owned(
+          Self::slice_positions(chars.len(), bounds)
+            .map(|position| chars[position])
+            .collect(),
+        )))
+      }
+      value => Err(Error::new(
+        list.1,
+        format!("Cannot slice {}", value.type_name()),
+      )),
+    }
+  }
+
+  fn evaluate_slice_bounds(
+    &mut self,
+    slice: &Slice,
+  ) -> Result<[Option<i64>; 3], Error> {
+    let mut bounds = [None; 3];
+
+    for (bound, expression) in bounds.iter_mut().zip(slice.bounds()) {
+      let Some(expression) = expression else {
+        continue;
+      };
+
+      let integer = self
+        .evaluate_expression(expression)?
+        .number(expression.1)?
+        .to_integer()
+        .ok_or_else(|| {
+          Error::new(expression.1, "Slice bound must be a finite integer")
+        })?;
+
+      *bound = Some(integer.to_i64().unwrap_or(if integer.is_negative() {
+        i64::MIN
+      } else {
+        i64::MAX
+      }));
+    }
+
+    if let (Some(0), Some(step)) = (bounds[2], &slice.step) {
+      return Err(Error::new(step.1, "Slice step cannot be zero"));
+    }
+
+    Ok(bounds)
+  }
+
   pub(crate) fn evaluate_statement(
     &mut self,
     statement: &Spanned<Statement>,
@@ -404,6 +887,26 @@ impl<'a> Evaluator<'a> {
 
         Ok(Completion::Break)
       }
+      Statement::CompoundAssignment(lhs, op, rhs) => {
+        let rhs = (self.evaluate_expression(rhs)?, rhs.1);
+
+        let mut result = Value::Null;
+
+        self.update(lhs, |evaluator, current| {
+          let value = evaluator.arithmetic(op, (current?, lhs.1), rhs)?;
+          result = value.clone();
+          Ok(value)
+        })?;
+
+        Ok(Completion::Value(result))
+      }
+      Statement::Const(target, expression) => {
+        let value = self.evaluate_expression(expression)?;
+
+        self.bind(target, value.clone(), true)?;
+
+        Ok(Completion::Value(value))
+      }
       Statement::Continue => {
         if !self.context.inside_loop() {
           return Err(Error::new(
@@ -417,14 +920,19 @@ impl<'a> Evaluator<'a> {
       Statement::Expression(expression) => {
         Ok(Completion::Value(self.evaluate_expression(expression)?))
       }
-      Statement::For(name, iterable, body) => {
-        let list = self.evaluate_expression(iterable)?.into_list(iterable.1)?;
+      Statement::For(target, iterable, body) => {
+        let items: Box<dyn Iterator<Item = Value<'a>>> =
+          match self.evaluate_expression(iterable)? {
+            Value::Map(map) => Box::new(map.into_keys().map(Value::from)),
+            Value::Range(range) => Box::new(range.iter().map(Value::Number)),
+            value => Box::new(value.into_list(iterable.1)?.into_iter()),
+          };
 
         let mut result = Value::Null;
 
         self.enter_loop(|evaluator| {
-          for item in list {
-            evaluator.environment.add_symbol(name, item);
+          for item in items {
+            evaluator.bind(target, item, false)?;
 
             for statement in body {
               let completion = evaluator.evaluate_statement(statement)?;
@@ -461,15 +969,62 @@ impl<'a> Evaluator<'a> {
 
         Ok(Completion::Value(Value::Function(function)))
       }
-      Statement::If(condition, then_branch, else_branch) => {
-        if self.evaluate_expression(condition)?.boolean(condition.1)? {
-          self.evaluate_statements(then_branch)
-        } else if let Some(else_statements) = else_branch {
-          self.evaluate_statements(else_statements)
-        } else {
-          Ok(Completion::Value(Value::Null))
+      Statement::If(branches, else_branch) => {
+        for (condition, body) in branches {
+          if self.evaluate_expression(condition)?.boolean(condition.1)? {
+            return self.evaluate_statements(body);
+          }
+        }
+
+        match else_branch {
+          Some(else_statements) => self.evaluate_statements(else_statements),
+          None => Ok(Completion::Value(Value::Null)),
         }
       }
+      Statement::Import(path, alias) => {
+        let module = self.import(path)?;
+
+        let name = match alias {
+          Some((alias, _)) => alias.clone(),
+          None => Path::new(&path.0)
+            .file_stem()
+            .and_then(OsStr::to_str)
+            .filter(|stem| {
+              stem
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && stem.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+            .map(str::to_owned)
+            .ok_or_else(|| {
+              Error::new(
+                path.1,
+                format!(
+                  "Cannot derive a module name from `{}`, use `as` to name it",
+                  path.0
+                ),
+              )
+            })?,
+        };
+
+        self
+          .environment
+          .add_symbol(&name, module.clone())
+          .map_err(|error| error.with_span(*span))?;
+
+        Ok(Completion::Value(module))
+      }
+      Statement::Let(target, expression) => {
+        let value = match expression {
+          Some(expression) => self.evaluate_expression(expression)?,
+          None => Value::Null,
+        };
+
+        self.bind(target, value.clone(), false)?;
+
+        Ok(Completion::Value(value))
+      }
       Statement::Loop(body) => self.enter_loop(|evaluator| {
         loop {
           for statement in body {
@@ -498,6 +1053,13 @@ impl<'a> Evaluator<'a> {
           None => Value::Null,
         }))
       }
+      Statement::Throw(expression) => {
+        let value = self.evaluate_expression(expression)?;
+        Err(self.throw(value, expression.1))
+      }
+      Statement::Try(body, catch, finally) => {
+        self.evaluate_try(body, catch.as_ref(), finally.as_deref())
+      }
       Statement::While(condition, body) => {
         let mut result = Value::Null;
 
@@ -552,6 +1114,540 @@ impl<'a> Evaluator<'a> {
 
     Ok(Completion::Value(result))
   }
+
+  fn evaluate_try(
+    &mut self,
+    body: &[Spanned<Statement>],
+    catch: Option<&(Spanned<String>, Vec<Spanned<Statement>>)>,
+    finally: Option<&[Spanned<Statement>]>,
+  ) -> Result<Completion<'a>, Error> {
+    let mut result = self.evaluate_statements(body);
+
+    if let Some(((name, name_span), handler)) = catch {
+      result = match result {
+        Err(error) if !matches!(error, Error::Exit { .. }) => {
+          let environment = Environment::with_parent(self.environment.clone());
+
+          environment
+            .add_symbol(name, self.exception(&error))
+            .map_err(|error| error.with_span(*name_span))?;
+
+          self.with_environment(environment, |evaluator| {
+            evaluator.evaluate_statements(handler)
+          })
+        }
+        result => result,
+      };
+    }
+
+    let Some(finally) = finally else {
+      return result;
+    };
+
+    if matches!(result, Err(Error::Exit { .. })) {
+      return result;
+    }
+
+    let pending = self.thrown.borrow_mut().take();
+
+    match self.evaluate_statements(finally)? {
+      Completion::Value(_) => {
+        *self.thrown.borrow_mut() = pending;
+        result
+      }
+      completion => Ok(completion),
+    }
+  }
+
+  fn exception(&self, error: &Error) -> Value<'a> {
+    let thrown = match error {
+      Error::Thrown { .. } => self.thrown.borrow_mut().take(),
+      _ => None,
+    };
+
+    match thrown {
+      Some(value @ Value::Error(_)) => value,
+      thrown => Value::Error(Rc::new(Exception {
+        kind: error.kind(),
+        message: error.to_string(),
+        span: error.span(),
+        value: thrown.unwrap_or(Value::Null),
+      })),
+    }
+  }
+
+  fn fixed(
+    op: &BinaryOp,
+    lhs: Spanned<Value<'a>>,
+    rhs: Spanned<Value<'a>>,
+  ) -> Result<Value<'a>, Error> {
+    let ((lhs, lhs_span), (rhs, rhs_span)) = (lhs, rhs);
+
+    let (ty, overflow) = match (&lhs, &rhs) {
+      (Value::Fixed(a), Value::Fixed(b)) if a.ty() != b.ty() => {
+        return Err(Error::new(
+          rhs_span,
+          format!(
+            "Cannot mix {} and {} operands, convert one with `{}(x)`",
+            a.ty(),
+            b.ty(),
+            a.ty()
+          ),
+        ));
+      }
+      (Value::Fixed(a), Value::Fixed(b)) => {
+        (a.ty(), a.overflow().strictest(b.overflow()))
+      }
+      (Value::Fixed(fixed), _) | (_, Value::Fixed(fixed)) => {
+        (fixed.ty(), fixed.overflow())
+      }
+      _ => unreachable!(),
+    };
+
+    Fixed::operate(
+      op,
+      ty,
+      overflow,
+      lhs.integer(lhs_span)?,
+      &rhs.integer(rhs_span)?,
+    )
+    .map(Value::Fixed)
+    .map_err(|error| error.with_span(rhs_span))
+  }
+
+  fn import(&mut self, path: &Spanned<String>) -> Result<Value<'a>, Error> {
+    let relative = self
+      .environment
+      .path
+      .as_deref()
+      .and_then(|path| path.parent())
+      .unwrap_or(Path::new(""))
+      .join(&path.0);
+
+    let unreadable = |error: std::io::Error| {
+      Error::new(path.1, format!("Cannot import `{}`: {error}", path.0))
+    };
+
+    let resolved = fs::canonicalize(&relative).map_err(unreadable)?;
+
+    let modules = self.environment.modules.clone();
+
+    if let Some(module) = modules.borrow().loaded.get(&resolved) {
+      return Ok(module.clone());
+    }
+
+    if let Some(position) =
+      modules.borrow().loading.iter().position(|p| *p == resolved)
+    {
+      let cycle = modules.borrow().loading[position..]
+        .iter()
+        .chain([&resolved])
+        .map(|p| p.file_name().unwrap_or_default().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" -> ");
+
+      return Err(Error::new(
+        path.1,
+        format!("Import cycle detected: {cycle}"),
+      ));
+    }
+
+    let text = fs::read_to_string(&resolved).map_err(unreadable)?;
+
+    modules.borrow_mut().loading.push(resolved.clone());
+
+    let source = self.add_source(relative.display().to_string(), &text);
+
+    let result = self.load_module(&resolved, &text, source);
+
+    modules.borrow_mut().loading.pop();
+
+    let module = result.map_err(|error| match error {
+      Error::Exit { code, .. } => Error::Exit { code, span: path.1 },
+      error => error,
+    })?;
+
+    modules.borrow_mut().loaded.insert(resolved, module.clone());
+
+    Ok(module)
+  }
+
+  fn load_module(
+    &mut self,
+    path: &Path,
+    text: &str,
+    source: SourceId,
+  ) -> Result<Value<'a>, Error> {
+    let (Program::Statements(statements), _) = parse_with_source(text, source)
+      .map_err(|errors| {
+        errors
+          .into_iter()
+          .next()
+          .unwrap_or_else(|| Error::Message("Invalid module".into()))
+      })?;
+
+    let environment = self.environment.module(path);
+
+    let context = std::mem::take(&mut self.context);
+
+    let result = self.with_environment(environment.clone(), |evaluator| {
+      evaluator.evaluate_statements(&statements)
+    });
+
+    self.context = context;
+
+    result.map(|_| Value::Map(environment.exports()))
+  }
+
+  fn match_pattern<'p>(
+    &mut self,
+    pattern: &'p Spanned<Pattern>,
+    value: &Value<'a>,
+    bindings: &mut Vec<(&'p str, Value<'a>)>,
+  ) -> Result<bool, Error> {
+    match &pattern.0 {
+      Pattern::Binding(name) => {
+        bindings.push((name, value.clone()));
+        Ok(true)
+      }
+      Pattern::List(elements, rest) => {
+        let Value::List(list) = value else {
+          return Ok(false);
+        };
+
+        if list.len() < elements.len()
+          || rest.is_none() && list.len() != elements.len()
+        {
+          return Ok(false);
+        }
+
+        for (element, value) in elements.iter().zip(list) {
+          if !self.match_pattern(element, value, bindings)? {
+            return Ok(false);
+          }
+        }
+
+        match rest {
+          Some(rest) => self.match_pattern(
+            rest,
+            &Value::List(list[elements.len()..].to_vec()),
+            bindings,
+          ),
+          None => Ok(true),
+        }
+      }
+      Pattern::Literal(literal) => {
+        Ok(self.evaluate_expression(&(literal.clone(), pattern.1))? == *value)
+      }
+      Pattern::Type(name, type_name) => {
+        if value.type_name() != type_name {
+          return Ok(false);
+        }
+
+        if let Some(name) = name {
+          bindings.push((name, value.clone()));
+        }
+
+        Ok(true)
+      }
+      Pattern::Wildcard => Ok(true),
+    }
+  }
+
+  fn matrix(
+    op: &BinaryOp,
+    lhs: Spanned<Value<'a>>,
+    rhs: Spanned<Value<'a>>,
+    config: Config,
+  ) -> Result<Value<'a>, Error> {
+    let ((lhs, _), (rhs, rhs_span)) = (lhs, rhs);
+
+    let apply = |lhs: &Number, rhs: &Number| match op {
+      BinaryOp::Add => Ok(lhs.add(rhs, config)),
+      BinaryOp::Divide => lhs.div(rhs, config),
+      BinaryOp::Modulo => lhs.rem(rhs, config),
+      BinaryOp::Hadamard | BinaryOp::Multiply => Ok(lhs.mul(rhs, config)),
+      BinaryOp::Subtract => Ok(lhs.sub(rhs, config)),
+      _ => unreachable!(),
+    };
+
+    match (op, &lhs, &rhs) {
+      (BinaryOp::Add, Value::Matrix(a), Value::Matrix(b)) => a.add(b, config),
+      (BinaryOp::Divide, Value::Matrix(a), Value::Matrix(b)) => {
+        a.div(b, config)
+      }
+      (BinaryOp::Hadamard, Value::Matrix(a), Value::Matrix(b)) => {
+        a.hadamard(b, config)
+      }
+      (BinaryOp::Modulo, Value::Matrix(a), Value::Matrix(b)) => {
+        a.rem(b, config)
+      }
+      (BinaryOp::Multiply, Value::Matrix(a), Value::Matrix(b)) => {
+        a.mul(b, config)
+      }
+      (
+        BinaryOp::Add
+        | BinaryOp::Divide
+        | BinaryOp::Hadamard
+        | BinaryOp::Modulo
+        | BinaryOp::Multiply
+        | BinaryOp::Subtract,
+        Value::Matrix(matrix),
+        Value::Number(scalar),
+      ) => matrix.map(|entry| apply(entry, scalar)),
+      (
+        BinaryOp::Add
+        | BinaryOp::Divide
+        | BinaryOp::Hadamard
+        | BinaryOp::Modulo
+        | BinaryOp::Multiply
+        | BinaryOp::Subtract,
+        Value::Number(scalar),
+        Value::Matrix(matrix),
+      ) => matrix.map(|entry| apply(scalar, entry)),
+      (BinaryOp::Power, Value::Matrix(matrix), exponent) => {
+        matrix.pow(&exponent.integer(rhs_span)?, config)
+      }
+      (BinaryOp::Subtract, Value::Matrix(a), Value::Matrix(b)) => {
+        a.sub(b, config)
+      }
+      _ => {
+        return Err(Error::new(
+          rhs_span,
+          format!(
+            "Cannot apply '{op}' to {} and {}",
+            lhs.type_name(),
+            rhs.type_name()
+          ),
+        ));
+      }
+    }
+    .map(Value::Matrix)
+    .map_err(|error| error.with_span(rhs_span))
+  }
+
+  fn polynomial(
+    op: &BinaryOp,
+    lhs: Spanned<Value<'a>>,
+    rhs: Spanned<Value<'a>>,
+    config: Config,
+  ) -> Result<Value<'a>, Error> {
+    let ((lhs, lhs_span), (rhs, rhs_span)) = (lhs, rhs);
+
+    let supported = match (&lhs, &rhs) {
+      (Value::Polynomial(_), Value::Number(_)) => true,
+      (Value::Number(_) | Value::Polynomial(_), Value::Polynomial(_)) => {
+        !matches!(op, BinaryOp::Power)
+      }
+      _ => false,
+    };
+
+    if !supported {
+      return Err(Error::new(
+        rhs_span,
+        format!(
+          "Cannot apply '{op}' to {} and {}",
+          lhs.type_name(),
+          rhs.type_name()
+        ),
+      ));
+    }
+
+    let a = lhs.polynomial(lhs_span)?;
+
+    if let BinaryOp::Power = op {
+      return a
+        .pow(&rhs.integer(rhs_span)?, config)
+        .map(Value::Polynomial)
+        .map_err(|error| error.with_span(rhs_span));
+    }
+
+    let b = rhs.polynomial(rhs_span)?;
+
+    match op {
+      BinaryOp::Add => Ok(a.add(&b, config)),
+      BinaryOp::Divide => a.div_rem(&b, config).map(|(quotient, _)| quotient),
+      BinaryOp::Modulo => a.div_rem(&b, config).map(|(_, remainder)| remainder),
+      BinaryOp::Multiply => Ok(a.mul(&b, config)),
+      BinaryOp::Subtract => Ok(a.sub(&b, config)),
+      _ => Err(Error::Message(format!(
+        "Cannot apply '{op}' to {} and {}",
+        lhs.type_name(),
+        rhs.type_name()
+      ))),
+    }
+    .map(Value::Polynomial)
+    .map_err(|error| error.with_span(rhs_span))
+  }
+
+  fn slice_positions(
+    len: usize,
+    bounds: [Option<i64>; 3],
+  ) -> impl Iterator<Item = usize> {
+    let (start, end, step) = Self::slice_range(len, bounds);
+
+    std::iter::successors(Some(start), move |position| Some(position + step))
+      .take_while(move |position| {
+        if step > 0 {
+          *position < end
+        } else {
+          *position > end
+        }
+      })
+      .map(|position| usize::try_from(position).unwrap())
+  }
+
+  fn slice_range(len: usize, bounds: [Option<i64>; 3]) -> (i128, i128, i128) {
+    let [start, end, step] = bounds.map(|bound| bound.map(i128::from));
+
+    let len = i128::try_from(len).unwrap();
+    let step = step.unwrap_or(1);
+
+    let (lower, upper) = if step < 0 { (-1, len - 1) } else { (0, len) };
+
+    let clamp = |bound: i128| {
+      if bound < 0 {
+        (bound + len).max(lower)
+      } else {
+        bound.min(upper)
+      }
+    };
+
+    let start = start.map_or(if step < 0 { upper } else { lower }, clamp);
+    let end = end.map_or(if step < 0 { lower } else { upper }, clamp);
+
+    (start, end, step)
+  }
+
+  #[must_use]
+  pub fn sources(&self) -> Sources {
+    self.sources.borrow().clone()
+  }
+
+  fn splice(
+    list: Value<'a>,
+    bounds: [Option<i64>; 3],
+    updated: impl FnOnce(Value<'a>) -> Result<Value<'a>, Error>,
+    span: Span,
+  ) -> Result<Value<'a>, Error> {
+    let Value::List(mut list) = list else {
+      return Err(Error::new(
+        span,
+        format!("Cannot assign to a slice of {}", list.type_name()),
+      ));
+    };
+
+    let positions =
+      Self::slice_positions(list.len(), bounds).collect::<Vec<_>>();
+
+    let replacement = match updated(Value::List(
+      positions
+        .iter()
+        .map(|&position| list[position].clone())
+        .collect(),
+    ))? {
+      Value::List(replacement) => replacement,
+      value => {
+        return Err(Error::new(
+          span,
+          format!("Cannot assign {} to a slice", value.type_name()),
+        ));
+      }
+    };
+
+    let (start, _, step) = Self::slice_range(list.len(), bounds);
+
+    if step == 1 {
+      let start = usize::try_from(start).unwrap();
+      list.splice(start..start + positions.len(), replacement);
+    } else if replacement.len() == positions.len() {
+      for (position, value) in positions.into_iter().zip(replacement) {
+        list[position] = value;
+      }
+    } else {
+      return Err(Error::new(
+        span,
+        format!(
+          "Cannot assign list of length {} to a slice of length {}",
+          replacement.len(),
+          positions.len()
+        ),
+      ));
+    }
+
+    Ok(Value::List(list))
+  }
+
+  fn throw(&self, value: Value<'a>, span: Span) -> Error {
+    let (message, span) = match &value {
+      Value::Error(exception) => (exception.message.clone(), exception.span),
+      value => (value.display(self.environment.config), span),
+    };
+
+    *self.thrown.borrow_mut() = Some(value);
+
+    Error::Thrown { message, span }
+  }
+
+  fn update(
+    &mut self,
+    target: &Spanned<AssignmentTarget>,
+    updated: impl FnOnce(
+      &Self,
+      Result<Value<'a>, Error>,
+    ) -> Result<Value<'a>, Error>,
+  ) -> Result<(), Error> {
+    let (name, name_span) = target.0.root(target.1);
+
+    let root = self.environment.resolve_symbol(name).ok_or_else(|| {
+      Error::new(name_span, format!("Undefined variable `{name}`"))
+    });
+
+    let root = match &target.0 {
+      AssignmentTarget::Identifier(_) => updated(self, root)?,
+      AssignmentTarget::ListAccess(_, _) => {
+        let indices = target.0.indices();
+
+        self.assign_indices(name, root?, &indices, updated, target.1)?
+      }
+      AssignmentTarget::Slice(base, slice) => {
+        let bounds = self.evaluate_slice_bounds(slice)?;
+        let indices = base.0.indices();
+
+        self.assign_indices(
+          name,
+          root?,
+          &indices,
+          |evaluator, list| {
+            Self::splice(
+              list?,
+              bounds,
+              |slice| updated(evaluator, Ok(slice)),
+              target.1,
+            )
+          },
+          target.1,
+        )?
+      }
+      AssignmentTarget::List(_) => unreachable!(),
+    };
+
+    self
+      .environment
+      .assign_symbol(name, root)
+      .map_err(|error| error.with_span(target.1))
+  }
+
+  fn with_environment<T>(
+    &mut self,
+    environment: Environment<'a>,
+    f: impl FnOnce(&mut Self) -> Result<T, Error>,
+  ) -> Result<T, Error> {
+    let previous = std::mem::replace(&mut self.environment, environment);
+    let result = f(self);
+    self.environment = previous;
+    result
+  }
 }
 
 impl<'a> From<Environment<'a>> for Evaluator<'a> {
@@ -559,6 +1655,8 @@ impl<'a> From<Environment<'a>> for Evaluator<'a> {
     Self {
       environment,
       context: Context::default(),
+      sources: Rc::default(),
+      thrown: Rc::default(),
     }
   }
 }
@@ -589,4 +1687,24 @@ mod tests {
     case("quit()", 0);
     case("quit(1)", 1);
   }
+
+  #[test]
+  fn spans_keep_their_source() {
+    let mut evaluator = Evaluator::from(Environment::new(Config::default()));
+
+    let library = evaluator.add_source("library.val", "fn f(x) { x / 0 }");
+
+    evaluator
+      .evaluate(&parse_with_source("fn f(x) { x / 0 }", library).unwrap())
+      .unwrap();
+
+    let input = evaluator.add_source("<input>", "f(1)");
+
+    let error = evaluator
+      .evaluate(&parse_with_source("f(1)", input).unwrap())
+      .unwrap_err();
+
+    assert_eq!(error.span(), library.span(14..15));
+    assert_eq!(evaluator.sources().name(library), Some("library.val"));
+  }
 }
@@ -0,0 +1,30 @@
+use super::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exception<'src> {
+  pub(crate) kind: &'static str,
+  pub(crate) message: String,
+  pub(crate) span: Span,
+  pub(crate) value: Value<'src>,
+}
+
+impl<'src> Exception<'src> {
+  pub(crate) fn get(&self, name: &str) -> Option<Value<'src>> {
+    match name {
+      "kind" => Some(Value::String(Cow::Borrowed(self.kind))),
+      "message" => Some(Value::String(Cow::Owned(self.message.clone()))),
+      "span" => Some(Value::List(vec![
+        Value::Number(Number::from(self.span.start)),
+        Value::Number(Number::from(self.span.end)),
+      ])),
+      "value" => Some(self.value.clone()),
+      _ => None,
+    }
+  }
+}
+
+impl Display for Exception<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "<error: {}>", self.message)
+  }
+}
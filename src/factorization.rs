@@ -0,0 +1,225 @@
+use super::*;
+
+const WITNESSES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Factorization {
+  factors: Vec<(Integer, u32)>,
+}
+
+impl Factorization {
+  pub(crate) fn divisors(&self) -> Vec<Integer> {
+    let mut divisors = vec![Integer::from(1)];
+
+    for (prime, exponent) in &self.factors {
+      let mut powers =
+        Vec::with_capacity(divisors.len() * (*exponent as usize));
+
+      let mut power = Integer::from(1);
+
+      for _ in 0..*exponent {
+        power *= prime;
+
+        powers.extend(divisors.iter().map(|divisor| divisor.clone() * &power));
+      }
+
+      divisors.extend(powers);
+    }
+
+    divisors.sort();
+
+    divisors
+  }
+
+  pub(crate) fn factors(&self) -> &[(Integer, u32)] {
+    &self.factors
+  }
+
+  pub(crate) fn is_prime(n: &Integer) -> bool {
+    if *n < 2 {
+      return false;
+    }
+
+    if n.significant_bits() > 64 {
+      return n.is_probably_prime(30) != IsPrime::No;
+    }
+
+    for witness in WITNESSES {
+      if *n == witness {
+        return true;
+      }
+
+      if n.is_divisible_u(witness) {
+        return false;
+      }
+    }
+
+    let predecessor = Integer::from(n - 1);
+
+    let shift = predecessor.find_one(0).unwrap_or_default();
+
+    let odd = Integer::from(&predecessor >> shift);
+
+    'witness: for witness in WITNESSES {
+      let mut x = Integer::from(witness).pow_mod(&odd, n).unwrap_or_default();
+
+      if x == 1 || x == predecessor {
+        continue;
+      }
+
+      for _ in 1..shift {
+        x = x.square() % n;
+
+        if x == predecessor {
+          continue 'witness;
+        }
+      }
+
+      return false;
+    }
+
+    true
+  }
+
+  pub(crate) fn new(n: &Integer) -> Self {
+    let mut remaining = n.clone().abs();
+
+    let mut primes = Vec::new();
+
+    for divisor in std::iter::once(2).chain((3..1000).step_by(2)) {
+      if remaining < divisor * divisor {
+        break;
+      }
+
+      while remaining.is_divisible_u(divisor) {
+        remaining /= divisor;
+        primes.push(Integer::from(divisor));
+      }
+    }
+
+    Self::split(remaining, &mut primes);
+
+    primes.sort();
+
+    let mut factors = Vec::<(Integer, u32)>::new();
+
+    for prime in primes {
+      match factors.last_mut() {
+        Some((last, exponent)) if *last == prime => *exponent += 1,
+        _ => factors.push((prime, 1)),
+      }
+    }
+
+    Self { factors }
+  }
+
+  fn pollard_rho(n: &Integer) -> Integer {
+    for increment in 1_u32.. {
+      let step = |x: &Integer| (x.clone().square() + increment) % n;
+
+      let (mut tortoise, mut hare) = (Integer::from(2), Integer::from(2));
+
+      loop {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+
+        let divisor = Integer::from(&tortoise - &hare).abs().gcd(n);
+
+        if divisor == *n {
+          break;
+        }
+
+        if divisor != 1 {
+          return divisor;
+        }
+      }
+    }
+
+    n.clone()
+  }
+
+  fn split(n: Integer, primes: &mut Vec<Integer>) {
+    if n == 1 {
+      return;
+    }
+
+    if Self::is_prime(&n) {
+      primes.push(n);
+      return;
+    }
+
+    let divisor = Self::pollard_rho(&n);
+
+    let quotient = Integer::from(&n / &divisor);
+
+    Self::split(divisor, primes);
+    Self::split(quotient, primes);
+  }
+
+  pub(crate) fn totient(&self) -> Integer {
+    self
+      .factors
+      .iter()
+      .fold(Integer::from(1), |totient, (prime, exponent)| {
+        totient
+          * Integer::from(prime - 1)
+          * Integer::from(prime.pow(exponent - 1))
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[test]
+  fn divisors_are_sorted() {
+    assert_eq!(
+      Factorization::new(&Integer::from(60)).divisors(),
+      [1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]
+    );
+  }
+
+  #[test]
+  fn factors_large_semiprimes() {
+    let n = Integer::from(4_294_967_291_u64) * 4_294_967_279_u64;
+
+    assert_eq!(
+      Factorization::new(&n).factors(),
+      [
+        (Integer::from(4_294_967_279_u64), 1),
+        (Integer::from(4_294_967_291_u64), 1)
+      ]
+    );
+  }
+
+  #[test]
+  fn factors_prime_powers() {
+    assert_eq!(
+      Factorization::new(&(Integer::from(1_000_003).pow(3) * 8)).factors(),
+      [(Integer::from(2), 3), (Integer::from(1_000_003), 3)]
+    );
+  }
+
+  #[test]
+  fn strong_pseudoprimes_are_composite() {
+    for n in [
+      2047_u64,
+      1_373_653,
+      3_215_031_751,
+      3_825_123_056_546_413_051,
+    ] {
+      assert!(!Factorization::is_prime(&Integer::from(n)), "{n}");
+    }
+
+    for n in [2_u64, 37, 1_000_003, 18_446_744_073_709_551_557] {
+      assert!(Factorization::is_prime(&Integer::from(n)), "{n}");
+    }
+  }
+
+  #[test]
+  fn totient() {
+    assert_eq!(Factorization::new(&Integer::from(36)).totient(), 12);
+    assert_eq!(Factorization::new(&Integer::from(1)).totient(), 1);
+  }
+}
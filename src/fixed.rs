@@ -0,0 +1,232 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed {
+  overflow: Overflow,
+  ty: FixedType,
+  value: i128,
+}
+
+impl Fixed {
+  fn clamp_exponent(exponent: &Integer) -> u32 {
+    match exponent.to_u32() {
+      Some(exponent) if exponent <= 129 => exponent,
+      _ if exponent.is_odd() => 129,
+      _ => 128,
+    }
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error when negating a checked value overflows.
+  pub fn negate(self) -> Result<Self, Error> {
+    Self::new(self.ty, -self.to_integer(), self.overflow).ok_or_else(|| {
+      Error::Message(format!("Integer overflow in {} arithmetic", self.ty))
+    })
+  }
+
+  #[must_use]
+  pub fn new(
+    ty: FixedType,
+    value: Integer,
+    overflow: Overflow,
+  ) -> Option<Self> {
+    let (min, max) = (ty.min(), ty.max());
+
+    let value = if value < min || value > max {
+      match overflow {
+        Overflow::Checked => return None,
+        Overflow::Saturating if value < min => Integer::from(min),
+        Overflow::Saturating => Integer::from(max),
+        Overflow::Wrapping => {
+          let value = value.keep_bits(ty.bits());
+
+          if value > max {
+            value - (Integer::from(1) << ty.bits())
+          } else {
+            value
+          }
+        }
+      }
+    } else {
+      value
+    };
+
+    Some(Self {
+      overflow,
+      ty,
+      value: value.to_i128()?,
+    })
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error when the result overflows a checked value, when
+  /// dividing by zero, or when an exponent or shift amount is negative.
+  pub fn operate(
+    op: &BinaryOp,
+    ty: FixedType,
+    overflow: Overflow,
+    lhs: Integer,
+    rhs: &Integer,
+  ) -> Result<Self, Error> {
+    let result = match op {
+      BinaryOp::Add => lhs + rhs,
+      BinaryOp::BitwiseAnd => {
+        return Ok(Self::wrap(ty, overflow, lhs & rhs));
+      }
+      BinaryOp::BitwiseOr => return Ok(Self::wrap(ty, overflow, lhs | rhs)),
+      BinaryOp::BitwiseXor => {
+        return Ok(Self::wrap(ty, overflow, lhs ^ rhs));
+      }
+      BinaryOp::Divide if rhs.is_zero() => return Err(Error::DivisionByZero),
+      BinaryOp::Divide => lhs / rhs,
+      BinaryOp::Modulo if rhs.is_zero() => return Err(Error::ModuloByZero),
+      BinaryOp::Modulo => lhs % rhs,
+      BinaryOp::Hadamard | BinaryOp::Multiply => lhs * rhs,
+      BinaryOp::Power if rhs.is_negative() => {
+        return Err(Error::Message(format!(
+          "Cannot raise {ty} to a negative power"
+        )));
+      }
+      BinaryOp::Power if overflow == Overflow::Wrapping => {
+        let modulus = Integer::from(1) << ty.bits();
+
+        lhs.pow_mod(rhs, &modulus).unwrap_or_default()
+      }
+      BinaryOp::Power => lhs.pow(Self::clamp_exponent(rhs)),
+      BinaryOp::ShiftLeft | BinaryOp::ShiftRight if rhs.is_negative() => {
+        return Err(Error::Message("Shift amount must not be negative".into()));
+      }
+      BinaryOp::ShiftLeft => lhs << rhs.to_u32().unwrap_or(u32::MAX).min(128),
+      BinaryOp::ShiftRight => lhs >> rhs.to_u32().unwrap_or(u32::MAX).min(128),
+      BinaryOp::Subtract => lhs - rhs,
+      _ => unreachable!(),
+    };
+
+    Self::new(ty, result, overflow).ok_or_else(|| {
+      Error::Message(format!("Integer overflow in {ty} arithmetic"))
+    })
+  }
+
+  #[must_use]
+  pub fn overflow(self) -> Overflow {
+    self.overflow
+  }
+
+  #[must_use]
+  pub fn to_integer(self) -> Integer {
+    Integer::from(self.value)
+  }
+
+  #[must_use]
+  pub fn ty(self) -> FixedType {
+    self.ty
+  }
+
+  fn wrap(ty: FixedType, overflow: Overflow, value: Integer) -> Self {
+    let Self { ty, value, .. } =
+      Self::new(ty, value, Overflow::Wrapping).unwrap();
+
+    Self {
+      overflow,
+      ty,
+      value,
+    }
+  }
+}
+
+impl Display for Fixed {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}{}", self.value, self.ty)
+  }
+}
+
+impl From<Fixed> for Number {
+  fn from(fixed: Fixed) -> Self {
+    Number::from(fixed.to_integer())
+  }
+}
+
+impl Not for Fixed {
+  type Output = Self;
+
+  fn not(self) -> Self {
+    Self::wrap(self.ty, self.overflow, !self.to_integer())
+  }
+}
+
+impl PartialEq for Fixed {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[test]
+  fn new_applies_overflow_mode() {
+    #[track_caller]
+    fn case(ty: FixedType, value: i64, overflow: Overflow, expected: &str) {
+      assert_eq!(
+        Fixed::new(ty, Integer::from(value), overflow)
+          .map_or_else(|| "overflow".into(), |fixed| fixed.to_string()),
+        expected
+      );
+    }
+
+    case(FixedType::U8, 255, Overflow::Checked, "255u8");
+    case(FixedType::U8, 256, Overflow::Checked, "overflow");
+    case(FixedType::U8, 256, Overflow::Saturating, "255u8");
+    case(FixedType::U8, -1, Overflow::Saturating, "0u8");
+    case(FixedType::U8, 256, Overflow::Wrapping, "0u8");
+    case(FixedType::U8, -1, Overflow::Wrapping, "255u8");
+    case(FixedType::I8, 128, Overflow::Wrapping, "-128i8");
+    case(FixedType::I8, -129, Overflow::Wrapping, "127i8");
+    case(FixedType::I16, 40000, Overflow::Saturating, "32767i16");
+    case(
+      FixedType::U64,
+      -1,
+      Overflow::Wrapping,
+      "18446744073709551615u64",
+    );
+  }
+
+  #[test]
+  fn power_overflow() {
+    #[track_caller]
+    fn case(base: i64, exponent: u64, overflow: Overflow, expected: &str) {
+      assert_eq!(
+        Fixed::operate(
+          &BinaryOp::Power,
+          FixedType::I64,
+          overflow,
+          Integer::from(base),
+          &Integer::from(exponent),
+        )
+        .map_or_else(|error| error.to_string(), |fixed| fixed.to_string()),
+        expected
+      );
+    }
+
+    case(-1, u64::MAX, Overflow::Checked, "-1i64");
+    case(-2, 63, Overflow::Checked, "-9223372036854775808i64");
+    case(
+      2,
+      63,
+      Overflow::Checked,
+      "Integer overflow in i64 arithmetic",
+    );
+    case(
+      -2,
+      1_000_001,
+      Overflow::Saturating,
+      "-9223372036854775808i64",
+    );
+    case(2, 1_000_000, Overflow::Saturating, "9223372036854775807i64");
+    case(2, 1_000_000, Overflow::Wrapping, "0i64");
+    case(3, 1_000_000, Overflow::Wrapping, "7682401271709541633i64");
+  }
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixedType {
+  I16,
+  I32,
+  I64,
+  I8,
+  U16,
+  U32,
+  U64,
+  U8,
+}
+
+impl FixedType {
+  #[must_use]
+  pub fn bits(self) -> u32 {
+    match self {
+      Self::I8 | Self::U8 => 8,
+      Self::I16 | Self::U16 => 16,
+      Self::I32 | Self::U32 => 32,
+      Self::I64 | Self::U64 => 64,
+    }
+  }
+
+  #[must_use]
+  pub fn is_signed(self) -> bool {
+    matches!(self, Self::I8 | Self::I16 | Self::I32 | Self::I64)
+  }
+
+  #[must_use]
+  pub fn max(self) -> i128 {
+    if self.is_signed() {
+      (1 << (self.bits() - 1)) - 1
+    } else {
+      (1 << self.bits()) - 1
+    }
+  }
+
+  #[must_use]
+  pub fn min(self) -> i128 {
+    if self.is_signed() {
+      -(1 << (self.bits() - 1))
+    } else {
+      0
+    }
+  }
+
+  #[must_use]
+  pub fn name(self) -> &'static str {
+    match self {
+      Self::I16 => "i16",
+      Self::I32 => "i32",
+      Self::I64 => "i64",
+      Self::I8 => "i8",
+      Self::U16 => "u16",
+      Self::U32 => "u32",
+      Self::U64 => "u64",
+      Self::U8 => "u8",
+    }
+  }
+}
+
+impl Display for FixedType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
@@ -12,22 +12,59 @@ pub enum Function<'src> {
     environment: Environment<'src>,
     identity: Rc<()>,
     name: Option<String>,
-    parameters: Vec<String>,
+    parameters: Vec<Parameter>,
   },
 }
 
 impl<'src> Function<'src> {
+  fn arity(&self) -> BuiltinArity {
+    match self {
+      Self::Builtin { arity, .. } => *arity,
+      Self::UserDefined { parameters, .. } => {
+        let (mut required, mut optional, mut rest) = (0, 0, false);
+
+        for parameter in parameters {
+          match parameter {
+            Parameter::Default(_, _) => optional += 1,
+            Parameter::Positional(_) => required += 1,
+            Parameter::Rest(_) => rest = true,
+          }
+        }
+
+        match (optional, rest) {
+          (_, true) if required == 0 => BuiltinArity::Any,
+          (_, true) => BuiltinArity::AtLeast(required),
+          (0, false) => BuiltinArity::Exact(required),
+          (optional, false) => {
+            BuiltinArity::Range(required, required + optional)
+          }
+        }
+      }
+    }
+  }
+
   pub(crate) fn call(
     &self,
+    caller: &Evaluator<'src>,
     arguments: Vec<Value<'src>>,
-    config: Config,
+    named: Vec<(&str, Value<'src>)>,
     span: Span,
   ) -> Result<Value<'src>, Error> {
     match self {
       Self::Builtin { function, .. } => {
+        if !named.is_empty() {
+          return Err(Error::new(
+            span,
+            format!(
+              "Function `{}` does not accept named arguments",
+              self.name()
+            ),
+          ));
+        }
+
         function.call(&BuiltinFunctionPayload {
           arguments,
-          config,
+          config: caller.environment.config,
           span,
         })
       }
@@ -44,11 +81,50 @@ impl<'src> Function<'src> {
           call_environment.add_function(name, self.clone());
         }
 
-        for (parameter, argument) in parameters.iter().zip(arguments) {
-          call_environment.add_symbol(parameter, argument);
+        let mut slots = parameters.iter().map(|_| None).collect::<Vec<_>>();
+
+        for (name, value) in named {
+          if let Some(index) = Self::parameter_index(parameters, name) {
+            slots[index] = Some(value);
+          }
         }
 
-        Evaluator::from(call_environment).enter_function(|evaluator| {
+        let mut arguments = arguments.into_iter();
+
+        let mut evaluator = Evaluator {
+          context: Context::default(),
+          environment: call_environment,
+          sources: caller.sources.clone(),
+          thrown: caller.thrown.clone(),
+        };
+
+        evaluator.enter_function(|evaluator| {
+          for (parameter, named) in parameters.iter().zip(slots) {
+            let value = match (parameter, arguments.next(), named) {
+              (Parameter::Rest(_), argument, _) => Value::List(
+                argument.into_iter().chain(&mut arguments).collect(),
+              ),
+              (_, Some(value), _) | (_, None, Some(value)) => value,
+              (Parameter::Default(_, default), None, None) => {
+                evaluator.evaluate_expression(default)?
+              }
+              (Parameter::Positional(name), None, None) => {
+                return Err(Error::new(
+                  span,
+                  format!(
+                    "Function `{}` is missing argument `{name}`",
+                    self.name()
+                  ),
+                ));
+              }
+            };
+
+            evaluator
+              .environment
+              .add_symbol(parameter.name(), value)
+              .map_err(|error| error.with_span(span))?;
+          }
+
           match evaluator.evaluate_statements(body)? {
             Completion::Return(value) | Completion::Value(value) => Ok(value),
             Completion::Break | Completion::Continue => Ok(Value::Null),
@@ -58,29 +134,43 @@ impl<'src> Function<'src> {
     }
   }
 
-  pub(crate) fn check_arity(
+  pub(crate) fn check_arguments(
     &self,
-    len: usize,
+    positional: usize,
+    named: &[Spanned<&str>],
     span: Span,
   ) -> Result<(), Error> {
-    match self {
-      Self::Builtin { arity, name, .. } => arity.check(name, len, span),
-      Self::UserDefined { parameters, .. } => {
-        if parameters.len() == len {
-          return Ok(());
+    if let Self::UserDefined { parameters, .. } = self {
+      let mut seen = Vec::with_capacity(named.len());
+
+      for &(name, name_span) in named {
+        let Some(index) = Self::parameter_index(parameters, name) else {
+          return Err(Error::new(
+            name_span,
+            format!(
+              "Function `{}` has no parameter named `{name}`",
+              self.name()
+            ),
+          ));
+        };
+
+        if index < positional || seen.contains(&index) {
+          return Err(Error::new(
+            name_span,
+            format!(
+              "Function `{}` got multiple values for argument `{name}`",
+              self.name()
+            ),
+          ));
         }
 
-        Err(Error::new(
-          span,
-          format!(
-            "Function `{}` expects {} arguments, got {}",
-            self.name(),
-            parameters.len(),
-            len
-          ),
-        ))
+        seen.push(index);
       }
     }
+
+    self
+      .arity()
+      .check(self.name(), positional + named.len(), span)
   }
 
   pub(crate) fn name(&self) -> &str {
@@ -91,6 +181,12 @@ impl<'src> Function<'src> {
       }
     }
   }
+
+  fn parameter_index(parameters: &[Parameter], name: &str) -> Option<usize> {
+    parameters.iter().position(|parameter| {
+      !matches!(parameter, Parameter::Rest(_)) && parameter.name() == name
+    })
+  }
 }
 
 impl PartialEq for Function<'_> {
@@ -52,6 +52,15 @@ impl<'src> Highlighter<'src> {
       } else if let Some(end) = self.scan_comment(cursor) {
         spans.push(HighlightSpan::new(cursor, end, HighlightKind::Comment));
 
+        cursor = end;
+      } else if let Some((end, interpolation)) = self.scan_interpolation(cursor)
+      {
+        spans.extend(interpolation);
+
+        cursor = end;
+      } else if let Some(end) = self.scan_raw_string(cursor) {
+        spans.push(HighlightSpan::new(cursor, end, HighlightKind::String));
+
         cursor = end;
       } else if character == '_' || character.is_alphabetic() {
         let end = self.scan_identifier(cursor);
@@ -105,8 +114,9 @@ impl<'src> Highlighter<'src> {
 
     match token {
       "false" | "true" => HighlightKind::Boolean,
-      "break" | "continue" | "else" | "fn" | "for" | "if" | "in" | "loop"
-      | "null" | "return" | "while" => HighlightKind::Keyword,
+      "as" | "break" | "catch" | "const" | "continue" | "else" | "finally"
+      | "fn" | "for" | "if" | "import" | "in" | "let" | "loop" | "match"
+      | "null" | "return" | "throw" | "try" | "while" => HighlightKind::Keyword,
       _ if self.next_non_padding_char(end) == Some('(') => {
         HighlightKind::Function
       }
@@ -194,7 +204,10 @@ impl<'src> Highlighter<'src> {
     let mut end = start;
 
     while let Some(character) = self.content[end..].chars().next() {
-      if character == '_' || character.is_alphanumeric() {
+      if character == '_'
+        || character.is_alphabetic()
+        || character.is_ascii_digit()
+      {
         end += character.len_utf8();
       } else {
         break;
@@ -204,24 +217,155 @@ impl<'src> Highlighter<'src> {
     end
   }
 
+  fn scan_interpolated_expression(&self, start: usize) -> usize {
+    let mut depth = 0;
+
+    let mut end = start;
+
+    while let Some(character) = self.content[end..].chars().next() {
+      match character {
+        '{' => depth += 1,
+        '}' => {
+          depth -= 1;
+
+          if depth == 0 {
+            return end;
+          }
+        }
+        '"' | '\'' => {
+          end = self.scan_string(end, character);
+          continue;
+        }
+        _ => {}
+      }
+
+      end += character.len_utf8();
+    }
+
+    end
+  }
+
+  fn scan_interpolation(
+    &self,
+    start: usize,
+  ) -> Option<(usize, Vec<HighlightSpan>)> {
+    let quote = self.content[start..]
+      .strip_prefix('f')?
+      .chars()
+      .next()
+      .filter(|character| matches!(character, '"' | '\''))?;
+
+    let mut spans = Vec::new();
+
+    let mut segment = start;
+
+    let mut end = start + 'f'.len_utf8() + quote.len_utf8();
+
+    while let Some(character) = self.content[end..].chars().next() {
+      if character == '\\' {
+        end += character.len_utf8();
+
+        if let Some(escaped) = self.content[end..].chars().next() {
+          end += escaped.len_utf8();
+        }
+      } else if self.content[end..].starts_with("{{")
+        || self.content[end..].starts_with("}}")
+      {
+        end += 2;
+      } else if character == '{' {
+        spans.push(HighlightSpan::new(segment, end, HighlightKind::String));
+
+        let close = self.scan_interpolated_expression(end);
+
+        spans.push(HighlightSpan::new(end, end + 1, HighlightKind::Operator));
+
+        spans.extend(
+          Highlighter::new(&self.content[end + 1..close])
+            .collect_highlight_spans()
+            .into_iter()
+            .map(|span| {
+              HighlightSpan::new(
+                span.start + end + 1,
+                span.end + end + 1,
+                span.kind,
+              )
+            }),
+        );
+
+        if close < self.content.len() {
+          spans.push(HighlightSpan::new(
+            close,
+            close + 1,
+            HighlightKind::Operator,
+          ));
+        }
+
+        end = (close + 1).min(self.content.len());
+
+        segment = end;
+      } else {
+        end += character.len_utf8();
+
+        if character == quote {
+          break;
+        }
+      }
+    }
+
+    spans.push(HighlightSpan::new(segment, end, HighlightKind::String));
+
+    Some((end, spans))
+  }
+
   fn scan_number(&self, start: usize) -> usize {
     let bytes = self.content.as_bytes();
 
-    let mut end = start;
+    let scan_digits = |mut end: usize, radix: u32| {
+      while end < bytes.len()
+        && ((bytes[end] as char).is_digit(radix)
+          || bytes[end] == b'_'
+            && end + 1 < bytes.len()
+            && (bytes[end + 1] as char).is_digit(radix))
+      {
+        end += 1;
+      }
+
+      end
+    };
+
+    let radix = match bytes.get(start..start + 2) {
+      Some(b"0b" | b"0B") => Some(2),
+      Some(b"0o" | b"0O") => Some(8),
+      Some(b"0x" | b"0X") => Some(16),
+      _ => None,
+    };
+
+    if let Some(radix) = radix {
+      let end = scan_digits(start + 2, radix);
+
+      if end > start + 2 {
+        return end;
+      }
+    }
+
+    let mut end = scan_digits(start, 10);
 
-    while end < bytes.len() && bytes[end].is_ascii_digit() {
-      end += 1;
+    if end + 1 < bytes.len()
+      && bytes[end] == b'.'
+      && bytes[end + 1].is_ascii_digit()
+    {
+      end = scan_digits(end + 1, 10);
     }
 
-    if end + 1 < bytes.len() && bytes[end] == b'.' {
-      let mut decimal_end = end + 1;
+    if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+      let mut exponent = end + 1;
 
-      while decimal_end < bytes.len() && bytes[decimal_end].is_ascii_digit() {
-        decimal_end += 1;
+      if exponent < bytes.len() && matches!(bytes[exponent], b'+' | b'-') {
+        exponent += 1;
       }
 
-      if decimal_end > end + 1 {
-        end = decimal_end;
+      if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+        end = scan_digits(exponent, 10);
       }
     }
 
@@ -229,12 +373,28 @@ impl<'src> Highlighter<'src> {
   }
 
   fn scan_operator(&self, start: usize) -> Option<usize> {
-    for operator in [">=", "<=", "==", "!=", "&&", "||"] {
+    for operator in [
+      "...", "..=", "<<=", ">>=", "..", "=>", ">=", "<=", "==", "!=", "&&",
+      "||", "??", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "~=", "<<",
+      ">>",
+    ] {
       if self.content[start..].starts_with(operator) {
         return Some(start + operator.len());
       }
     }
 
+    let exponent = self.content[start..]
+      .chars()
+      .take_while(|character| {
+        matches!(character, '⁻' | '⁰' | '¹' | '²' | '³' | '⁴'..='⁹')
+      })
+      .map(char::len_utf8)
+      .sum::<usize>();
+
+    if exponent > 0 {
+      return Some(start + exponent);
+    }
+
     let character = self.content[start..].chars().next()?;
 
     matches!(
@@ -246,7 +406,10 @@ impl<'src> Highlighter<'src> {
         | '{'
         | '}'
         | ','
+        | '.'
+        | ':'
         | ';'
+        | '?'
         | '+'
         | '-'
         | '*'
@@ -257,17 +420,53 @@ impl<'src> Highlighter<'src> {
         | '<'
         | '='
         | '!'
+        | '&'
+        | '|'
+        | '~'
+        | '×'
+        | '÷'
+        | '−'
+        | '≤'
+        | '≥'
+        | '≠'
+        | '√'
     )
     .then_some(start + character.len_utf8())
   }
 
+  fn scan_raw_string(&self, start: usize) -> Option<usize> {
+    let quote = self.content[start..]
+      .strip_prefix('r')?
+      .chars()
+      .next()
+      .filter(|character| matches!(character, '"' | '\''))?;
+
+    let mut end = start + 'r'.len_utf8() + quote.len_utf8();
+
+    while let Some(character) = self.content[end..].chars().next() {
+      end += character.len_utf8();
+
+      if character == quote {
+        break;
+      }
+    }
+
+    Some(end)
+  }
+
   fn scan_string(&self, start: usize, quote: char) -> usize {
     let mut end = start + quote.len_utf8();
 
+    let mut escaped = false;
+
     while let Some(character) = self.content[end..].chars().next() {
       end += character.len_utf8();
 
-      if character == quote {
+      if escaped {
+        escaped = false;
+      } else if character == '\\' {
+        escaped = true;
+      } else if character == quote {
         break;
       }
     }
@@ -299,6 +498,25 @@ mod tests {
     );
   }
 
+  #[test]
+  fn bitwise_operators() {
+    let highlighter = Highlighter::new("a & ~b << 2 |= 1");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 1, HighlightKind::Identifier),
+        HighlightSpan::new(2, 3, HighlightKind::Operator),
+        HighlightSpan::new(4, 5, HighlightKind::Operator),
+        HighlightSpan::new(5, 6, HighlightKind::Identifier),
+        HighlightSpan::new(7, 9, HighlightKind::Operator),
+        HighlightSpan::new(10, 11, HighlightKind::Number),
+        HighlightSpan::new(12, 14, HighlightKind::Operator),
+        HighlightSpan::new(15, 16, HighlightKind::Number),
+      ]
+    );
+  }
+
   #[test]
   fn comments() {
     let highlighter = Highlighter::new("x = 1 // foo\n// bar");
@@ -339,6 +557,127 @@ mod tests {
     );
   }
 
+  #[test]
+  fn imports() {
+    let highlighter = Highlighter::new("import \"lib.val\" as lib\nlib.f(1)");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 6, HighlightKind::Keyword),
+        HighlightSpan::new(7, 16, HighlightKind::String),
+        HighlightSpan::new(17, 19, HighlightKind::Keyword),
+        HighlightSpan::new(20, 23, HighlightKind::Identifier),
+        HighlightSpan::new(24, 27, HighlightKind::Identifier),
+        HighlightSpan::new(27, 28, HighlightKind::Operator),
+        HighlightSpan::new(28, 29, HighlightKind::Function),
+        HighlightSpan::new(29, 30, HighlightKind::Operator),
+        HighlightSpan::new(30, 31, HighlightKind::Number),
+        HighlightSpan::new(31, 32, HighlightKind::Operator),
+      ]
+    );
+  }
+
+  #[test]
+  fn interpolation() {
+    let highlighter = Highlighter::new("f\"a {x + 1} {{b}} {'}'}\"");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 4, HighlightKind::String),
+        HighlightSpan::new(4, 5, HighlightKind::Operator),
+        HighlightSpan::new(5, 6, HighlightKind::Identifier),
+        HighlightSpan::new(7, 8, HighlightKind::Operator),
+        HighlightSpan::new(9, 10, HighlightKind::Number),
+        HighlightSpan::new(10, 11, HighlightKind::Operator),
+        HighlightSpan::new(11, 18, HighlightKind::String),
+        HighlightSpan::new(18, 19, HighlightKind::Operator),
+        HighlightSpan::new(19, 22, HighlightKind::String),
+        HighlightSpan::new(22, 23, HighlightKind::Operator),
+        HighlightSpan::new(23, 24, HighlightKind::String),
+      ]
+    );
+  }
+
+  #[test]
+  fn match_arms() {
+    let highlighter = Highlighter::new("match (x) { [a, ...b] => a }");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 5, HighlightKind::Keyword),
+        HighlightSpan::new(6, 7, HighlightKind::Operator),
+        HighlightSpan::new(7, 8, HighlightKind::Identifier),
+        HighlightSpan::new(8, 9, HighlightKind::Operator),
+        HighlightSpan::new(10, 11, HighlightKind::Operator),
+        HighlightSpan::new(12, 13, HighlightKind::Operator),
+        HighlightSpan::new(13, 14, HighlightKind::Identifier),
+        HighlightSpan::new(14, 15, HighlightKind::Operator),
+        HighlightSpan::new(16, 19, HighlightKind::Operator),
+        HighlightSpan::new(19, 20, HighlightKind::Identifier),
+        HighlightSpan::new(20, 21, HighlightKind::Operator),
+        HighlightSpan::new(22, 24, HighlightKind::Operator),
+        HighlightSpan::new(25, 26, HighlightKind::Identifier),
+        HighlightSpan::new(27, 28, HighlightKind::Operator),
+      ]
+    );
+  }
+
+  #[test]
+  fn number_literals() {
+    let highlighter = Highlighter::new("0xff + 1_000 - 2.5e-3 * 1e");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 4, HighlightKind::Number),
+        HighlightSpan::new(5, 6, HighlightKind::Operator),
+        HighlightSpan::new(7, 12, HighlightKind::Number),
+        HighlightSpan::new(13, 14, HighlightKind::Operator),
+        HighlightSpan::new(15, 21, HighlightKind::Number),
+        HighlightSpan::new(22, 23, HighlightKind::Operator),
+        HighlightSpan::new(24, 25, HighlightKind::Number),
+        HighlightSpan::new(25, 26, HighlightKind::Identifier),
+      ]
+    );
+  }
+
+  #[test]
+  fn ranges() {
+    let highlighter = Highlighter::new("x in 1..=10 || 2.5..n");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 1, HighlightKind::Identifier),
+        HighlightSpan::new(2, 4, HighlightKind::Keyword),
+        HighlightSpan::new(5, 6, HighlightKind::Number),
+        HighlightSpan::new(6, 9, HighlightKind::Operator),
+        HighlightSpan::new(9, 11, HighlightKind::Number),
+        HighlightSpan::new(12, 14, HighlightKind::Operator),
+        HighlightSpan::new(15, 18, HighlightKind::Number),
+        HighlightSpan::new(18, 20, HighlightKind::Operator),
+        HighlightSpan::new(20, 21, HighlightKind::Identifier),
+      ]
+    );
+  }
+
+  #[test]
+  fn raw_strings() {
+    let highlighter = Highlighter::new("r'\\' + r");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 4, HighlightKind::String),
+        HighlightSpan::new(5, 6, HighlightKind::Operator),
+        HighlightSpan::new(7, 8, HighlightKind::Identifier),
+      ]
+    );
+  }
+
   #[test]
   fn string_contents_are_not_highlighted_as_tokens() {
     let highlighter = Highlighter::new("\"if\" + 'else'");
@@ -352,4 +691,39 @@ mod tests {
       ]
     );
   }
+
+  #[test]
+  fn string_escapes() {
+    let highlighter = Highlighter::new("\"a\\\"b\" + 'c\\\\'");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 6, HighlightKind::String),
+        HighlightSpan::new(7, 8, HighlightKind::Operator),
+        HighlightSpan::new(9, 14, HighlightKind::String),
+      ]
+    );
+  }
+
+  #[test]
+  fn unicode_operators() {
+    let highlighter = Highlighter::new("x² × √y ≤ 10⁻³ ≠ π");
+
+    assert_eq!(
+      highlighter.collect_highlight_spans(),
+      [
+        HighlightSpan::new(0, 1, HighlightKind::Identifier),
+        HighlightSpan::new(1, 3, HighlightKind::Operator),
+        HighlightSpan::new(4, 6, HighlightKind::Operator),
+        HighlightSpan::new(7, 10, HighlightKind::Operator),
+        HighlightSpan::new(10, 11, HighlightKind::Identifier),
+        HighlightSpan::new(12, 15, HighlightKind::Operator),
+        HighlightSpan::new(16, 18, HighlightKind::Number),
+        HighlightSpan::new(18, 23, HighlightKind::Operator),
+        HighlightSpan::new(24, 27, HighlightKind::Operator),
+        HighlightSpan::new(28, 30, HighlightKind::Identifier),
+      ]
+    );
+  }
 }
@@ -0,0 +1,496 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct Interval {
+  lower: Float,
+  upper: Float,
+}
+
+impl Interval {
+  #[must_use]
+  pub fn acos(&self, precision: u32) -> Self {
+    self.decreasing(precision, Float::acos_round)
+  }
+
+  #[must_use]
+  pub fn add(&self, rhs: &Self, precision: u32) -> Self {
+    Self {
+      lower: Float::with_val_round(
+        precision,
+        &self.lower + &rhs.lower,
+        Round::Down,
+      )
+      .0,
+      upper: Float::with_val_round(
+        precision,
+        &self.upper + &rhs.upper,
+        Round::Up,
+      )
+      .0,
+    }
+  }
+
+  #[must_use]
+  pub fn asin(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::asin_round)
+  }
+
+  #[must_use]
+  pub fn atan(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::atan_round)
+  }
+
+  fn bound(
+    precision: u32,
+    value: &Float,
+    round: Round,
+    function: impl Fn(&mut Float, Round) -> Ordering,
+  ) -> Float {
+    let mut bound = Float::with_val_round(precision, value, round).0;
+    function(&mut bound, round);
+    bound
+  }
+
+  #[must_use]
+  pub fn ceil(&self) -> Self {
+    Self {
+      lower: self.lower.clone().ceil(),
+      upper: self.upper.clone().ceil(),
+    }
+  }
+
+  #[must_use]
+  pub fn contains_zero(&self) -> bool {
+    self.lower <= 0 && self.upper >= 0
+  }
+
+  fn corners(
+    &self,
+    rhs: &Self,
+    precision: u32,
+    operation: impl Fn(&Float, &Float, Round) -> Float,
+  ) -> Self {
+    let corners = |round: Round| {
+      [
+        (&self.lower, &rhs.lower),
+        (&self.lower, &rhs.upper),
+        (&self.upper, &rhs.lower),
+        (&self.upper, &rhs.upper),
+      ]
+      .map(|(lhs, rhs)| {
+        let corner = operation(lhs, rhs, round);
+
+        if corner.is_nan() {
+          Float::with_val(precision, 0)
+        } else {
+          corner
+        }
+      })
+    };
+
+    Self {
+      lower: corners(Round::Down)
+        .into_iter()
+        .reduce(|lhs, rhs| lhs.min(&rhs))
+        .unwrap(),
+      upper: corners(Round::Up)
+        .into_iter()
+        .reduce(|lhs, rhs| lhs.max(&rhs))
+        .unwrap(),
+    }
+  }
+
+  #[must_use]
+  pub fn cos(&self, precision: u32) -> Self {
+    self.periodic(precision, Float::cos_round, 0, 2)
+  }
+
+  #[must_use]
+  pub fn cosh(&self, precision: u32) -> Self {
+    self.magnitude().increasing(precision, Float::cosh_round)
+  }
+
+  fn decreasing(
+    &self,
+    precision: u32,
+    function: impl Fn(&mut Float, Round) -> Ordering,
+  ) -> Self {
+    Self {
+      lower: Self::bound(precision, &self.upper, Round::Down, &function),
+      upper: Self::bound(precision, &self.lower, Round::Up, &function),
+    }
+  }
+
+  #[must_use]
+  pub fn div(&self, rhs: &Self, precision: u32) -> Option<Self> {
+    if rhs.contains_zero() {
+      return None;
+    }
+
+    if self.is_nan() || rhs.is_nan() {
+      return Some(Self::nan(precision));
+    }
+
+    Some(self.corners(rhs, precision, |lhs, rhs, round| {
+      Float::with_val_round(precision, lhs / rhs, round).0
+    }))
+  }
+
+  #[must_use]
+  pub fn enclose(number: &Rational, precision: u32) -> Self {
+    Self {
+      lower: Float::with_val_round(precision, number, Round::Down).0,
+      upper: Float::with_val_round(precision, number, Round::Up).0,
+    }
+  }
+
+  #[must_use]
+  pub fn exp(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::exp_round)
+  }
+
+  #[must_use]
+  pub fn factorial(&self, precision: u32) -> Option<Self> {
+    if self.is_nan() || self.lower < 1 {
+      return None;
+    }
+
+    Some(
+      self
+        .add(&Self::point(Float::with_val(precision, 1)), precision)
+        .increasing(precision, Float::gamma_round),
+    )
+  }
+
+  #[must_use]
+  pub fn floor(&self) -> Self {
+    Self {
+      lower: self.lower.clone().floor(),
+      upper: self.upper.clone().floor(),
+    }
+  }
+
+  fn increasing(
+    &self,
+    precision: u32,
+    function: impl Fn(&mut Float, Round) -> Ordering,
+  ) -> Self {
+    Self {
+      lower: Self::bound(precision, &self.lower, Round::Down, &function),
+      upper: Self::bound(precision, &self.upper, Round::Up, &function),
+    }
+  }
+
+  #[must_use]
+  pub fn is_nan(&self) -> bool {
+    self.lower.is_nan() || self.upper.is_nan()
+  }
+
+  #[must_use]
+  pub fn is_point(&self) -> bool {
+    self.lower == self.upper
+  }
+
+  #[must_use]
+  pub fn ln(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::ln_round)
+  }
+
+  #[must_use]
+  pub fn log10(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::log10_round)
+  }
+
+  #[must_use]
+  pub fn log2(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::log2_round)
+  }
+
+  #[must_use]
+  pub fn lower(&self) -> &Float {
+    &self.lower
+  }
+
+  #[must_use]
+  pub fn magnitude(&self) -> Self {
+    if self.lower >= 0 {
+      self.clone()
+    } else if self.upper <= 0 {
+      self.neg()
+    } else {
+      Self {
+        lower: Float::with_val(self.lower.prec(), 0),
+        upper: Float::max(-self.lower.clone(), &self.upper),
+      }
+    }
+  }
+
+  #[must_use]
+  pub fn midpoint(&self, precision: u32) -> Float {
+    Float::with_val(precision.saturating_add(1), &self.lower + &self.upper) / 2
+  }
+
+  #[must_use]
+  pub fn mul(&self, rhs: &Self, precision: u32) -> Self {
+    if self.is_nan() || rhs.is_nan() {
+      return Self::nan(precision);
+    }
+
+    self.corners(rhs, precision, |lhs, rhs, round| {
+      Float::with_val_round(precision, lhs * rhs, round).0
+    })
+  }
+
+  fn nan(precision: u32) -> Self {
+    Self::point(Float::with_val(precision, rug::float::Special::Nan))
+  }
+
+  #[must_use]
+  pub fn neg(&self) -> Self {
+    Self {
+      lower: -self.upper.clone(),
+      upper: -self.lower.clone(),
+    }
+  }
+
+  #[must_use]
+  pub fn new(lower: Float, upper: Float) -> Option<Self> {
+    (lower <= upper).then_some(Self { lower, upper })
+  }
+
+  fn periodic(
+    &self,
+    precision: u32,
+    function: impl Fn(&mut Float, Round) -> Ordering,
+    maximum: i64,
+    minimum: i64,
+  ) -> Self {
+    if !self.lower.is_finite() || !self.upper.is_finite() {
+      return Self {
+        lower: Float::with_val(precision, -1),
+        upper: Float::with_val(precision, 1),
+      };
+    }
+
+    let lower = Float::min(
+      Self::bound(precision, &self.lower, Round::Down, &function),
+      &Self::bound(precision, &self.upper, Round::Down, &function),
+    );
+
+    let upper = Float::max(
+      Self::bound(precision, &self.lower, Round::Up, &function),
+      &Self::bound(precision, &self.upper, Round::Up, &function),
+    );
+
+    Self {
+      lower: if self.reaches(precision, minimum, 4) {
+        Float::with_val(precision, -1)
+      } else {
+        lower.max(&Float::with_val(precision, -1))
+      },
+      upper: if self.reaches(precision, maximum, 4) {
+        Float::with_val(precision, 1)
+      } else {
+        upper.min(&Float::with_val(precision, 1))
+      },
+    }
+  }
+
+  #[must_use]
+  pub fn pi(precision: u32) -> Self {
+    Self {
+      lower: Float::with_val_round(precision, Constant::Pi, Round::Down).0,
+      upper: Float::with_val_round(precision, Constant::Pi, Round::Up).0,
+    }
+  }
+
+  #[must_use]
+  pub fn point(number: Float) -> Self {
+    Self {
+      lower: number.clone(),
+      upper: number,
+    }
+  }
+
+  #[must_use]
+  pub fn pow(&self, exponent: &Integer, precision: u32) -> Self {
+    let base = if exponent.is_even() {
+      self.magnitude()
+    } else {
+      self.clone()
+    };
+
+    Self {
+      lower: Float::with_val_round(
+        precision,
+        (&base.lower).pow(exponent),
+        Round::Down,
+      )
+      .0,
+      upper: Float::with_val_round(
+        precision,
+        (&base.upper).pow(exponent),
+        Round::Up,
+      )
+      .0,
+    }
+  }
+
+  #[must_use]
+  pub fn radius(&self, precision: u32) -> Float {
+    let midpoint = self.midpoint(precision);
+
+    Float::max(
+      Float::with_val_round(precision, &midpoint - &self.lower, Round::Up).0,
+      &Float::with_val_round(precision, &self.upper - &midpoint, Round::Up).0,
+    )
+  }
+
+  fn reaches(&self, precision: u32, offset: i64, period: i64) -> bool {
+    let turns = self
+      .mul(&Self::enclose(&Rational::from(2), precision), precision)
+      .div(&Self::pi(precision), precision)
+      .unwrap()
+      .sub(
+        &Self::enclose(&Rational::from(offset), precision),
+        precision,
+      )
+      .div(
+        &Self::enclose(&Rational::from(period), precision),
+        precision,
+      )
+      .unwrap();
+
+    turns.lower.clone().ceil() <= turns.upper.clone().floor()
+  }
+
+  #[must_use]
+  pub fn sin(&self, precision: u32) -> Self {
+    self.periodic(precision, Float::sin_round, 1, 3)
+  }
+
+  #[must_use]
+  pub fn sinh(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::sinh_round)
+  }
+
+  #[must_use]
+  pub fn sqrt(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::sqrt_round)
+  }
+
+  #[must_use]
+  pub fn sub(&self, rhs: &Self, precision: u32) -> Self {
+    self.add(&rhs.neg(), precision)
+  }
+
+  #[must_use]
+  pub fn tan(&self, precision: u32) -> Self {
+    if !self.lower.is_finite()
+      || !self.upper.is_finite()
+      || self.reaches(precision, 1, 2)
+    {
+      return Self {
+        lower: Float::with_val(precision, rug::float::Special::NegInfinity),
+        upper: Float::with_val(precision, rug::float::Special::Infinity),
+      };
+    }
+
+    self.increasing(precision, Float::tan_round)
+  }
+
+  #[must_use]
+  pub fn tanh(&self, precision: u32) -> Self {
+    self.increasing(precision, Float::tanh_round)
+  }
+
+  #[must_use]
+  pub fn upper(&self) -> &Float {
+    &self.upper
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[track_caller]
+  fn bounds(interval: &Interval) -> (f64, f64) {
+    (interval.lower.to_f64(), interval.upper.to_f64())
+  }
+
+  fn interval(lower: f64, upper: f64) -> Interval {
+    Interval::new(Float::with_val(53, lower), Float::with_val(53, upper))
+      .unwrap()
+  }
+
+  #[test]
+  fn enclose_rounds_outward() {
+    let third = Interval::enclose(&Rational::from((1, 3)), 53);
+
+    assert!(third.lower < third.upper);
+    assert!(third.lower < Rational::from((1, 3)));
+    assert!(third.upper > Rational::from((1, 3)));
+  }
+
+  #[test]
+  fn multiplication_uses_all_corners() {
+    assert_eq!(
+      bounds(&interval(-1.0, 2.0).mul(&interval(-3.0, 4.0), 53)),
+      (-6.0, 8.0)
+    );
+
+    assert_eq!(
+      bounds(&interval(-2.0, -1.0).mul(&interval(3.0, 4.0), 53)),
+      (-8.0, -3.0)
+    );
+  }
+
+  #[test]
+  fn division_by_interval_containing_zero() {
+    assert!(interval(1.0, 2.0).div(&interval(-1.0, 1.0), 53).is_none());
+
+    assert_eq!(
+      bounds(&interval(1.0, 2.0).div(&interval(4.0, 8.0), 53).unwrap()),
+      (0.125, 0.5)
+    );
+  }
+
+  #[test]
+  fn even_powers_fold_negative_bounds() {
+    assert_eq!(
+      bounds(&interval(-2.0, 3.0).pow(&Integer::from(2), 53)),
+      (0.0, 9.0)
+    );
+
+    assert_eq!(
+      bounds(&interval(-2.0, 3.0).pow(&Integer::from(3), 53)),
+      (-8.0, 27.0)
+    );
+  }
+
+  #[test]
+  fn factorial_is_increasing_from_one() {
+    let factorial = interval(2.0, 3.0).factorial(53).unwrap();
+
+    assert!(factorial.lower <= 2 && factorial.upper >= 6);
+    assert!(factorial.lower > 1.9 && factorial.upper < 6.1);
+
+    assert!(interval(0.5, 3.0).factorial(53).is_none());
+  }
+
+  #[test]
+  fn trigonometric_extremes() {
+    assert_eq!(interval(1.0, 2.0).sin(53).upper, 1);
+    assert_eq!(interval(3.0, 3.5).cos(53).lower, -1);
+    assert_eq!(bounds(&interval(-10.0, 10.0).sin(53)), (-1.0, 1.0));
+
+    let sine = interval(0.0, 1.0).sin(53);
+
+    assert_eq!(sine.lower, 0);
+    assert!(sine.upper >= 1.0_f64.sin() && sine.upper < 1);
+
+    assert!(interval(1.0, 2.0).tan(53).upper.is_infinite());
+    assert!(interval(0.0, 1.0).tan(53).upper.is_finite());
+  }
+}
@@ -0,0 +1,38 @@
+use super::*;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Key<'src> {
+  Boolean(bool),
+  Null,
+  Number(Number),
+  String(Cow<'src, str>),
+}
+
+impl Key<'_> {
+  #[must_use]
+  pub fn display(&self, config: Config) -> String {
+    match self {
+      Key::Boolean(boolean) => boolean.to_string(),
+      Key::Null => "null".into(),
+      Key::Number(number) => number.display(config),
+      Key::String(string) => format!("'{string}'"),
+    }
+  }
+}
+
+impl Display for Key<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.display(Config::default()))
+  }
+}
+
+impl<'src> From<Key<'src>> for Value<'src> {
+  fn from(key: Key<'src>) -> Self {
+    match key {
+      Key::Boolean(boolean) => Value::Boolean(boolean),
+      Key::Null => Value::Null,
+      Key::Number(number) => Value::Number(number),
+      Key::String(string) => Value::String(string),
+    }
+  }
+}
@@ -1,15 +1,22 @@
 use {
-  ariadne::{Color, IndexType, Label, Report, ReportKind},
-  ast::{AssignmentTarget, BinaryOp, Expression, Program, Statement, UnaryOp},
+  ariadne::{Cache, Color, IndexType, Label, Report, ReportKind, Source},
+  ast::{
+    AssignmentTarget, BinaryOp, Expression, MatchArm, Parameter, Pattern,
+    Program, Slice, Statement, UnaryOp,
+  },
   builtins::BUILTINS,
   chumsky::prelude::*,
   context::Context,
   decimal::Decimal,
+  exception::Exception,
+  factorization::Factorization,
   frame::Frame,
+  indexmap::IndexMap,
+  modules::Modules,
   rug::{
-    Complete, Float, Integer, Rational,
+    Complete, Complex, Float, Integer, Rational,
     float::{Constant, Round},
-    integer::MiniInteger,
+    integer::{IsPrime, MiniInteger},
     ops::Pow,
   },
   std::{
@@ -17,9 +24,13 @@ use {
     cell::RefCell,
     cmp::Ordering,
     collections::HashMap,
+    ffi::OsStr,
     fmt::{self, Display, Formatter},
+    fs,
+    hash::{Hash, Hasher},
     num::NonZeroUsize,
-    ops::Range,
+    ops::Not,
+    path::{Path, PathBuf},
     rc::Rc,
     str::FromStr,
   },
@@ -27,15 +38,34 @@ use {
 };
 
 pub use crate::{
-  builtin::Builtin, builtin_arity::BuiltinArity,
+  builtin::Builtin,
+  builtin_arity::BuiltinArity,
   builtin_function::BuiltinFunction,
-  builtin_function_payload::BuiltinFunctionPayload, completion::Completion,
-  config::Config, environment::Environment, error::Error,
-  evaluation::Evaluation, evaluator::Evaluator, function::Function,
-  number::Number, parser::parse, rounding_mode::RoundingMode, value::Value,
+  builtin_function_payload::BuiltinFunctionPayload,
+  completion::Completion,
+  config::Config,
+  environment::Environment,
+  error::Error,
+  evaluation::Evaluation,
+  evaluator::Evaluator,
+  fixed::Fixed,
+  fixed_type::FixedType,
+  function::Function,
+  interval::Interval,
+  key::Key,
+  matrix::Matrix,
+  number::Number,
+  overflow::Overflow,
+  parser::{parse, parse_with_source},
+  polynomial::Polynomial,
+  range::Range,
+  rounding_mode::RoundingMode,
+  source_id::SourceId,
+  sources::Sources,
+  value::Value,
 };
 
-pub type Span = SimpleSpan<usize>;
+pub type Span = SimpleSpan<usize, SourceId>;
 pub type Spanned<T> = (T, Span);
 
 type Result<T = (), E = anyhow::Error> = std::result::Result<T, E>;
@@ -54,10 +84,23 @@ mod environment;
 mod error;
 mod evaluation;
 mod evaluator;
+mod exception;
+mod factorization;
+mod fixed;
+mod fixed_type;
 mod frame;
 mod function;
+mod interval;
+mod key;
+mod matrix;
+mod modules;
 mod number;
+mod overflow;
 mod parser;
+mod polynomial;
+mod range;
 mod rounding_mode;
+mod source_id;
+mod sources;
 mod symbol;
 mod value;
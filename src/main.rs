@@ -1,6 +1,5 @@
 use {
   arguments::Arguments,
-  ariadne::Source,
   clap::Parser,
   highlight_kind::HighlightKind,
   highlight_span::HighlightSpan,
@@ -29,7 +28,10 @@ use {
     str::FromStr,
     thread,
   },
-  val::{Config, Environment, Evaluation, Evaluator, Value, parse},
+  val::{
+    Config, Environment, Evaluation, Evaluator, Overflow, Value, parse,
+    parse_with_source,
+  },
 };
 
 mod arguments;
@@ -0,0 +1,633 @@
+use super::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+  columns: usize,
+  entries: Vec<Number>,
+  rows: usize,
+}
+
+impl Matrix {
+  /// # Errors
+  ///
+  /// Returns an error if the matrices have different shapes.
+  pub fn add(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    self.zip(rhs, "add", |lhs, rhs| Ok(lhs.add(rhs, config)))
+  }
+
+  fn at(&self, row: usize, column: usize) -> &Number {
+    &self.entries[row * self.columns + column]
+  }
+
+  fn augment(&self, rhs: &Self) -> Self {
+    let columns = self.columns + rhs.columns;
+
+    let mut entries = Vec::with_capacity(self.rows * columns);
+
+    for row in 0..self.rows {
+      entries.extend_from_slice(
+        &self.entries[row * self.columns..(row + 1) * self.columns],
+      );
+
+      entries.extend_from_slice(
+        &rhs.entries[row * rhs.columns..(row + 1) * rhs.columns],
+      );
+    }
+
+    Self {
+      columns,
+      entries,
+      rows: self.rows,
+    }
+  }
+
+  fn characteristic_polynomial(
+    &self,
+    config: Config,
+  ) -> Result<Vec<Number>, Error> {
+    let size = self.square("find the eigenvalues of")?;
+
+    let mut coefficients = vec![Number::from(1_i64)];
+
+    let mut power = Self {
+      columns: size,
+      entries: vec![Number::from(0_i64); size * size],
+      rows: size,
+    };
+
+    for k in 1..=size {
+      let coefficient = coefficients.last().unwrap();
+
+      power = self.mul(&power, config)?.add(
+        &Self::identity(size).map(|one| Ok(one.mul(coefficient, config)))?,
+        config,
+      )?;
+
+      let product = self.mul(&power, config)?;
+
+      let trace = (0..size).fold(Number::from(0_i64), |trace, index| {
+        trace.add(product.at(index, index), config)
+      });
+
+      coefficients.push(trace.neg().div(&Number::from(k), config)?);
+    }
+
+    Ok(coefficients)
+  }
+
+  #[must_use]
+  pub fn columns(&self) -> usize {
+    self.columns
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrix isn't square.
+  pub fn determinant(&self, config: Config) -> Result<Number, Error> {
+    let size = self.square("take the determinant of")?;
+
+    let mut reduced = self.clone();
+
+    let (pivots, determinant) = reduced.row_reduce(size, config)?;
+
+    Ok(if pivots < size {
+      Number::from(0_i64)
+    } else {
+      determinant
+    })
+  }
+
+  #[must_use]
+  pub fn display(&self, config: Config) -> String {
+    let entries = self
+      .entries
+      .iter()
+      .map(|entry| entry.display(config))
+      .collect::<Vec<String>>();
+
+    let widths = (0..self.columns)
+      .map(|column| {
+        (0..self.rows)
+          .map(|row| entries[row * self.columns + column].chars().count())
+          .max()
+          .unwrap_or_default()
+      })
+      .collect::<Vec<usize>>();
+
+    (0..self.rows)
+      .map(|row| {
+        let (open, close) = match (row, self.rows) {
+          (_, 1) => ('[', ']'),
+          (0, _) => ('⎡', '⎤'),
+          (row, rows) if row + 1 == rows => ('⎣', '⎦'),
+          _ => ('⎢', '⎥'),
+        };
+
+        let cells = (0..self.columns)
+          .map(|column| {
+            format!(
+              "{:>width$}",
+              entries[row * self.columns + column],
+              width = widths[column]
+            )
+          })
+          .collect::<Vec<String>>()
+          .join("  ");
+
+        format!("{open}{cells}{close}")
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrices have different shapes or an entry of
+  /// `rhs` is zero.
+  pub fn div(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    self.zip(rhs, "divide", |lhs, rhs| lhs.div(rhs, config))
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrix isn't square.
+  pub fn eigenvalues(&self, config: Config) -> Result<Vec<Number>, Error> {
+    Polynomial::new(self.characteristic_polynomial(config)?).roots(config)
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `rows` is empty or its rows differ in length.
+  pub fn from_rows(rows: Vec<Vec<Number>>) -> Result<Self, Error> {
+    let columns = rows.first().map_or(0, Vec::len);
+
+    if columns == 0 {
+      return Err(Error::Message(
+        "Matrix must have at least one row and column".into(),
+      ));
+    }
+
+    if rows.iter().any(|row| row.len() != columns) {
+      return Err(Error::Message(
+        "Matrix rows must all have the same length".into(),
+      ));
+    }
+
+    Ok(Self {
+      columns,
+      rows: rows.len(),
+      entries: rows.into_iter().flatten().collect(),
+    })
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrices have different shapes.
+  pub fn hadamard(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    self.zip(rhs, "multiply", |lhs, rhs| Ok(lhs.mul(rhs, config)))
+  }
+
+  #[must_use]
+  pub fn identity(size: usize) -> Self {
+    Self {
+      columns: size,
+      entries: (0..size * size)
+        .map(|index| Number::from(index % (size + 1) == 0))
+        .collect(),
+      rows: size,
+    }
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrix isn't square or is singular.
+  pub fn inverse(&self, config: Config) -> Result<Self, Error> {
+    let size = self.square("invert")?;
+    self.solve(&Self::identity(size), config)
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `function` fails for any entry.
+  pub fn map(
+    &self,
+    function: impl Fn(&Number) -> Result<Number, Error>,
+  ) -> Result<Self, Error> {
+    Ok(Self {
+      columns: self.columns,
+      entries: self
+        .entries
+        .iter()
+        .map(function)
+        .collect::<Result<Vec<Number>, Error>>()?,
+      rows: self.rows,
+    })
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the column count of `self` differs from the row
+  /// count of `rhs`.
+  pub fn mul(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    if self.columns != rhs.rows {
+      return Err(Error::Message(format!(
+        "Cannot multiply {} and {} matrices",
+        self.shape(),
+        rhs.shape()
+      )));
+    }
+
+    let mut entries = Vec::with_capacity(self.rows * rhs.columns);
+
+    for row in 0..self.rows {
+      for column in 0..rhs.columns {
+        entries.push((0..self.columns).fold(
+          Number::from(0_i64),
+          |sum, index| {
+            sum.add(
+              &self.at(row, index).mul(rhs.at(index, column), config),
+              config,
+            )
+          },
+        ));
+      }
+    }
+
+    Ok(Self {
+      columns: rhs.columns,
+      entries,
+      rows: self.rows,
+    })
+  }
+
+  #[must_use]
+  pub fn neg(&self) -> Self {
+    Self {
+      columns: self.columns,
+      entries: self.entries.iter().map(Number::neg).collect(),
+      rows: self.rows,
+    }
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrix isn't square, or if `exponent` is
+  /// negative and the matrix is singular.
+  pub fn pow(&self, exponent: &Integer, config: Config) -> Result<Self, Error> {
+    let size = self.square("raise to a power")?;
+
+    let mut base = if exponent.is_negative() {
+      self.inverse(config)?
+    } else {
+      self.clone()
+    };
+
+    let mut remaining = exponent.clone().abs();
+
+    let mut result = Self::identity(size);
+
+    while remaining > 0 {
+      if remaining.is_odd() {
+        result = result.mul(&base, config)?;
+      }
+
+      base = base.mul(&base, config)?;
+      remaining >>= 1;
+    }
+
+    Ok(result)
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if an entry can't be divided during elimination.
+  pub fn rank(&self, config: Config) -> Result<usize, Error> {
+    let mut reduced = self.clone();
+    Ok(reduced.row_reduce(self.columns, config)?.0)
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrices have different shapes or an entry of
+  /// `rhs` is zero.
+  pub fn rem(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    self.zip(rhs, "take the remainder of", |lhs, rhs| {
+      lhs.rem(rhs, config)
+    })
+  }
+
+  fn row_reduce(
+    &mut self,
+    limit: usize,
+    config: Config,
+  ) -> Result<(usize, Number), Error> {
+    let (mut pivots, mut determinant) = (0, Number::from(1_i64));
+
+    let precision = config.precision();
+
+    let thresholds = (0..limit)
+      .map(|column| {
+        (0..self.rows).fold(Float::with_val(precision, 0), |scale, row| {
+          scale.max(&self.at(row, column).abs(config).to_float(config))
+        }) >> (precision - precision / 8)
+      })
+      .collect::<Vec<Float>>();
+
+    let negligible = |entry: &Number, threshold: &Float| {
+      if entry.is_exact() {
+        entry.is_zero()
+      } else {
+        entry.abs(config).to_float(config) <= *threshold
+      }
+    };
+
+    for (column, threshold) in thresholds.iter().enumerate() {
+      if pivots == self.rows {
+        break;
+      }
+
+      let Some(pivot) = (pivots..self.rows)
+        .filter(|row| !negligible(self.at(*row, column), threshold))
+        .max_by(|lhs, rhs| {
+          self
+            .at(*lhs, column)
+            .abs(config)
+            .partial_cmp(&self.at(*rhs, column).abs(config))
+            .unwrap_or(Ordering::Equal)
+            .then(rhs.cmp(lhs))
+        })
+      else {
+        continue;
+      };
+
+      if pivot != pivots {
+        for index in 0..self.columns {
+          self
+            .entries
+            .swap(pivot * self.columns + index, pivots * self.columns + index);
+        }
+
+        determinant = determinant.neg();
+      }
+
+      let value = self.at(pivots, column).clone();
+
+      determinant = determinant.mul(&value, config);
+
+      for index in 0..self.columns {
+        let entry = pivots * self.columns + index;
+        self.entries[entry] = self.entries[entry].div(&value, config)?;
+      }
+
+      for row in (0..self.rows).filter(|row| *row != pivots) {
+        let factor = self.at(row, column).clone();
+
+        if factor.is_zero() {
+          continue;
+        }
+
+        for index in 0..self.columns {
+          let product = factor.mul(self.at(pivots, index), config);
+          let entry = row * self.columns + index;
+          self.entries[entry] = self.entries[entry].sub(&product, config);
+        }
+      }
+
+      pivots += 1;
+    }
+
+    Ok((pivots, determinant))
+  }
+
+  #[must_use]
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  fn shape(&self) -> String {
+    format!("{}x{}", self.rows, self.columns)
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `self` isn't square, is singular, or has a different
+  /// row count than `rhs`.
+  pub fn solve(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    let size = self.square("solve with")?;
+
+    if rhs.rows != size {
+      return Err(Error::Message(format!(
+        "Cannot solve a {} system with a {} right-hand side",
+        self.shape(),
+        rhs.shape()
+      )));
+    }
+
+    let mut augmented = self.augment(rhs);
+
+    if augmented.row_reduce(size, config)?.0 < size {
+      return Err(Error::Message("Matrix is singular".into()));
+    }
+
+    Ok(Self {
+      columns: rhs.columns,
+      entries: (0..size)
+        .flat_map(|row| {
+          augmented.entries
+            [row * augmented.columns + size..(row + 1) * augmented.columns]
+            .to_vec()
+        })
+        .collect(),
+      rows: size,
+    })
+  }
+
+  fn square(&self, action: &str) -> Result<usize, Error> {
+    if self.rows == self.columns {
+      Ok(self.rows)
+    } else {
+      Err(Error::Message(format!(
+        "Cannot {action} a non-square {} matrix",
+        self.shape()
+      )))
+    }
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the matrices have different shapes.
+  pub fn sub(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    self.zip(rhs, "subtract", |lhs, rhs| Ok(lhs.sub(rhs, config)))
+  }
+
+  #[must_use]
+  pub fn to_list<'a>(&self) -> Vec<Value<'a>> {
+    if self.columns == 1 || self.rows == 1 {
+      return self.entries.iter().cloned().map(Value::Number).collect();
+    }
+
+    self
+      .entries
+      .chunks(self.columns)
+      .map(|row| Value::List(row.iter().cloned().map(Value::Number).collect()))
+      .collect()
+  }
+
+  #[must_use]
+  pub fn transpose(&self) -> Self {
+    Self {
+      columns: self.rows,
+      entries: (0..self.columns)
+        .flat_map(|column| {
+          (0..self.rows).map(move |row| self.at(row, column).clone())
+        })
+        .collect(),
+      rows: self.columns,
+    }
+  }
+
+  fn zip(
+    &self,
+    rhs: &Self,
+    action: &str,
+    function: impl Fn(&Number, &Number) -> Result<Number, Error>,
+  ) -> Result<Self, Error> {
+    if self.rows != rhs.rows || self.columns != rhs.columns {
+      return Err(Error::Message(format!(
+        "Cannot {action} {} and {} matrices",
+        self.shape(),
+        rhs.shape()
+      )));
+    }
+
+    Ok(Self {
+      columns: self.columns,
+      entries: self
+        .entries
+        .iter()
+        .zip(&rhs.entries)
+        .map(|(lhs, rhs)| function(lhs, rhs))
+        .collect::<Result<Vec<Number>, Error>>()?,
+      rows: self.rows,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  fn matrix(rows: &[&[i64]]) -> Matrix {
+    Matrix::from_rows(
+      rows
+        .iter()
+        .map(|row| row.iter().copied().map(Number::from).collect())
+        .collect(),
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn approximate_singular_matrix() {
+    let config = Config::default();
+
+    let root = Number::from(2_i64).sqrt(config).unwrap();
+
+    let matrix = Matrix::from_rows(vec![
+      vec![root.clone(), Number::from(2_i64)],
+      vec![Number::from(1_i64), root],
+    ])
+    .unwrap();
+
+    assert_eq!(matrix.determinant(config).unwrap(), Number::from(0_i64));
+    assert_eq!(matrix.rank(config).unwrap(), 1);
+    assert_eq!(
+      matrix.inverse(config).unwrap_err().to_string(),
+      "Matrix is singular"
+    );
+  }
+
+  #[test]
+  fn determinant_is_exact() {
+    let config = Config::default();
+
+    assert_eq!(
+      matrix(&[&[2, 0, 1], &[1, 3, 2], &[1, 1, 2]])
+        .determinant(config)
+        .unwrap(),
+      Number::from(6_i64)
+    );
+
+    assert_eq!(
+      matrix(&[&[1, 2], &[2, 4]]).determinant(config).unwrap(),
+      Number::from(0_i64)
+    );
+  }
+
+  #[test]
+  fn display_aligns_columns() {
+    let config = Config::default();
+
+    assert_eq!(
+      matrix(&[&[1, -20], &[300, 4]]).display(config),
+      "⎡  1  -20⎤\n⎣300    4⎦"
+    );
+
+    assert_eq!(matrix(&[&[1, 2, 3]]).display(config), "[1  2  3]");
+
+    assert_eq!(matrix(&[&[1], &[2], &[3]]).display(config), "⎡1⎤\n⎢2⎥\n⎣3⎦");
+  }
+
+  #[test]
+  fn inverse_round_trips() {
+    let config = Config::default();
+
+    let matrix = matrix(&[&[4, 7], &[2, 6]]);
+
+    assert_eq!(
+      matrix
+        .mul(&matrix.inverse(config).unwrap(), config)
+        .unwrap(),
+      Matrix::identity(2)
+    );
+  }
+
+  #[test]
+  fn rank_of_dependent_rows() {
+    assert_eq!(
+      matrix(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]])
+        .rank(Config::default())
+        .unwrap(),
+      2
+    );
+  }
+
+  #[test]
+  fn shape_errors() {
+    let config = Config::default();
+
+    assert_eq!(
+      matrix(&[&[1, 2]])
+        .mul(&matrix(&[&[1, 2]]), config)
+        .unwrap_err()
+        .to_string(),
+      "Cannot multiply 1x2 and 1x2 matrices"
+    );
+
+    assert_eq!(
+      matrix(&[&[1, 2]])
+        .determinant(config)
+        .unwrap_err()
+        .to_string(),
+      "Cannot take the determinant of a non-square 1x2 matrix"
+    );
+
+    assert_eq!(
+      matrix(&[&[1, 2], &[2, 4]])
+        .inverse(config)
+        .unwrap_err()
+        .to_string(),
+      "Matrix is singular"
+    );
+  }
+}
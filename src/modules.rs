@@ -0,0 +1,7 @@
+use super::*;
+
+#[derive(Debug, Default)]
+pub(crate) struct Modules<'src> {
+  pub(crate) loaded: HashMap<PathBuf, Value<'src>>,
+  pub(crate) loading: Vec<PathBuf>,
+}
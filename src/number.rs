@@ -1,28 +1,63 @@
 use super::*;
 
+const MAX_EXPONENT: u64 = 1_000_000;
+
+const MAX_FACTORIAL: u32 = 100_000;
+
 #[derive(Clone, Debug)]
 pub enum Number {
   Approx(Float),
+  Complex(Box<(Self, Self)>),
   Exact(Rational),
+  Interval(Box<Interval>),
 }
 
 impl Number {
   #[must_use]
-  pub fn abs(&self) -> Self {
+  pub fn abs(&self, config: Config) -> Self {
     match self {
       Self::Approx(number) => Self::Approx(number.clone().abs()),
+      Self::Complex(parts) => {
+        let (re, im) = parts.as_ref();
+
+        re.mul(re, config)
+          .add(&im.mul(im, config), config)
+          .square_root(config)
+      }
       Self::Exact(number) => Self::Exact(number.clone().abs()),
+      Self::Interval(number) => Self::Interval(Box::new(number.magnitude())),
     }
   }
 
-  #[must_use]
-  pub fn acos(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::acos_round)
+  /// # Errors
+  ///
+  /// Returns an error if `self` is an interval that extends outside the
+  /// domain of the arccosine.
+  pub fn acos(&self, config: Config) -> std::result::Result<Self, Error> {
+    self.domain(config, "Arccosine", Interval::acos)?;
+
+    Ok(self.approx_unary(
+      config,
+      Interval::acos,
+      Float::acos_round,
+      Complex::acos_round,
+    ))
   }
 
   #[must_use]
   pub fn add(&self, rhs: &Self, config: Config) -> Self {
     match (self, rhs) {
+      (Self::Complex(_), _) | (_, Self::Complex(_)) => {
+        let ((a, b), (c, d)) = (self.parts(), rhs.parts());
+        Self::complex(a.add(&c, config), b.add(&d, config))
+      }
+      (Self::Interval(_), _) | (_, Self::Interval(_)) => {
+        Self::Interval(Box::new(
+          self
+            .to_interval(config)
+            .add(&rhs.to_interval(config), config.precision()),
+        ))
+      }
       (Self::Approx(lhs), Self::Approx(rhs)) => Self::Approx(
         Float::with_val_round(
           config.precision(),
@@ -47,12 +82,39 @@ impl Number {
   }
 
   fn approx_pow(&self, rhs: &Self, config: Config) -> Self {
-    Self::Approx(
-      Float::with_val_round(
+    if config.interval && !self.is_complex() && !rhs.is_complex() {
+      let result = Self::Interval(Box::new(self.to_interval(config)))
+        .ln(config)
+        .map(|ln| ln.mul(rhs, config).exp(config));
+
+      if let Ok(result) = result
+        && !result.is_complex()
+      {
+        return result;
+      }
+    }
+
+    if !self.is_complex() && !rhs.is_complex() {
+      let (base, exponent) = (self.to_float(config), rhs.to_float(config));
+
+      let result = Float::with_val_round(
         config.precision(),
-        self.to_float(config).pow(rhs.to_float(config)),
+        (&base).pow(&exponent),
         config.rounding_mode,
       )
+      .0;
+
+      if !result.is_nan() || base.is_nan() || exponent.is_nan() {
+        return Self::Approx(result);
+      }
+    }
+
+    Self::from_complex(
+      Complex::with_val_round(
+        config.precision(),
+        self.to_complex(config).pow(rhs.to_complex(config)),
+        (config.rounding_mode, config.rounding_mode),
+      )
       .0,
     )
   }
@@ -60,60 +122,176 @@ impl Number {
   fn approx_unary(
     &self,
     config: Config,
-    f: impl FnOnce(&mut Float, Round) -> Ordering,
+    interval: impl FnOnce(&Interval, u32) -> Interval,
+    real: impl FnOnce(&mut Float, Round) -> Ordering,
+    complex: impl FnOnce(&mut Complex, (Round, Round)) -> (Ordering, Ordering),
   ) -> Self {
-    let mut number = self.to_float(config);
-    f(&mut number, config.rounding_mode);
-    Self::Approx(number)
+    if !self.is_complex()
+      && (config.interval || matches!(self, Self::Interval(_)))
+    {
+      let number = self.to_interval(config);
+      let result = interval(&number, config.precision());
+
+      if !result.is_nan() || number.is_nan() {
+        return Self::Interval(Box::new(result));
+      }
+    } else if !self.is_complex() {
+      let mut number = self.to_float(config);
+      let undefined = number.is_nan();
+
+      real(&mut number, config.rounding_mode);
+
+      if !number.is_nan() || undefined {
+        return Self::Approx(number);
+      }
+    }
+
+    let mut number = self.to_complex(config);
+    complex(&mut number, (config.rounding_mode, config.rounding_mode));
+    Self::from_complex(number)
   }
 
   #[must_use]
-  pub fn asin(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::asin_round)
+  pub fn arg(&self, config: Config) -> Self {
+    let (re, im) = self.parts();
+
+    Self::Approx(
+      Float::with_val_round(
+        config.precision(),
+        im.to_float(config).atan2(&re.to_float(config)),
+        config.rounding_mode,
+      )
+      .0,
+    )
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `self` is an interval that extends outside the
+  /// domain of the arcsine.
+  pub fn asin(&self, config: Config) -> std::result::Result<Self, Error> {
+    self.domain(config, "Arcsine", Interval::asin)?;
+
+    Ok(self.approx_unary(
+      config,
+      Interval::asin,
+      Float::asin_round,
+      Complex::asin_round,
+    ))
   }
 
   #[must_use]
   pub fn atan(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::atan_round)
+    self.approx_unary(
+      config,
+      Interval::atan,
+      Float::atan_round,
+      Complex::atan_round,
+    )
+  }
+
+  #[must_use]
+  pub fn bounds(&self) -> (Self, Self) {
+    match self {
+      Self::Interval(number) => (
+        Self::Approx(number.lower().clone()),
+        Self::Approx(number.upper().clone()),
+      ),
+      number => (number.clone(), number.clone()),
+    }
   }
 
   #[must_use]
   pub fn ceil(&self) -> Self {
     match self {
       Self::Approx(number) => Self::Approx(number.clone().ceil()),
+      Self::Complex(parts) => Self::complex(parts.0.ceil(), parts.1.ceil()),
       Self::Exact(number) => Self::Exact(number.clone().ceil()),
+      Self::Interval(number) => Self::Interval(Box::new(number.ceil())),
     }
   }
 
+  #[must_use]
+  pub fn complex(re: Self, im: Self) -> Self {
+    if im.is_zero() {
+      re
+    } else {
+      Self::Complex(Box::new((re, im)))
+    }
+  }
+
+  #[must_use]
+  pub fn conj(&self) -> Self {
+    let (re, im) = self.parts();
+    Self::complex(re, im.neg())
+  }
+
   #[must_use]
   pub fn cos(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::cos_round)
+    self.approx_unary(
+      config,
+      Interval::cos,
+      Float::cos_round,
+      Complex::cos_round,
+    )
   }
 
   #[must_use]
   pub fn cosh(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::cosh_round)
+    self.approx_unary(
+      config,
+      Interval::cosh,
+      Float::cosh_round,
+      Complex::cosh_round,
+    )
   }
 
   #[must_use]
   pub fn display(&self, config: Config) -> String {
     match self {
       Self::Approx(number) => {
-        let (negative, digits, point) = number.to_sign_string_exp_round(
-          10,
-          Some(config.digits.get()),
-          Round::Nearest,
-        );
-
-        match point {
-          Some(point) => Decimal::new(digits, negative, i64::from(point))
-            .display(config.digits),
-          None if digits == "0" || digits == "NaN" => digits.to_lowercase(),
-          None if negative => format!("-{digits}"),
-          None => digits,
+        Self::display_float(number, config.digits, Round::Nearest)
+      }
+      Self::Complex(parts) => {
+        let (re, im) = parts.as_ref();
+
+        let imaginary = match im.abs(config).display(config).as_str() {
+          "1" => String::from("i"),
+          magnitude @ ("inf" | "nan") => format!("{magnitude}*i"),
+          magnitude => format!("{magnitude}i"),
+        };
+
+        match (re.is_zero(), im.is_negative()) {
+          (true, true) => format!("-{imaginary}"),
+          (true, false) => imaginary,
+          (false, true) => format!("{}-{imaginary}", re.display(config)),
+          (false, false) => format!("{}+{imaginary}", re.display(config)),
         }
       }
       Self::Exact(number) if number.is_integer() => number.numer().to_string(),
+      Self::Interval(number) if number.is_nan() => String::from("nan"),
+      Self::Interval(number)
+        if !number.lower().is_finite() || !number.upper().is_finite() =>
+      {
+        format!(
+          "[{}, {}]",
+          Self::display_float(number.lower(), config.digits, Round::Down),
+          Self::display_float(number.upper(), config.digits, Round::Up),
+        )
+      }
+      Self::Interval(number) => format!(
+        "{} ± {}",
+        Self::display_float(
+          &number.midpoint(config.precision()),
+          config.digits,
+          Round::Nearest,
+        ),
+        Self::display_float(
+          &number.radius(config.precision()),
+          NonZeroUsize::MIN.saturating_add(1),
+          Round::Up,
+        ),
+      ),
       Self::Exact(number) => {
         if let Some(decimal) = Decimal::from_rational(number) {
           decimal.display(config.digits)
@@ -125,9 +303,30 @@ impl Number {
     }
   }
 
+  fn display_float(
+    number: &Float,
+    digits: NonZeroUsize,
+    round: Round,
+  ) -> String {
+    let (negative, digits_string, point) =
+      number.to_sign_string_exp_round(10, Some(digits.get()), round);
+
+    match point {
+      Some(point) => {
+        Decimal::new(digits_string, negative, i64::from(point)).display(digits)
+      }
+      None if digits_string == "0" || digits_string == "NaN" => {
+        digits_string.to_lowercase()
+      }
+      None if negative => format!("-{digits_string}"),
+      None => digits_string,
+    }
+  }
+
   /// # Errors
   ///
-  /// Returns [`Error::DivisionByZero`] if `rhs` is zero.
+  /// Returns [`Error::DivisionByZero`] if `rhs` is zero, or an error if `rhs`
+  /// is an interval containing zero.
   pub fn div(
     &self,
     rhs: &Self,
@@ -137,6 +336,32 @@ impl Number {
       Err(Error::DivisionByZero)
     } else {
       Ok(match (self, rhs) {
+        (Self::Complex(_), _) | (_, Self::Complex(_)) => {
+          let ((a, b), (c, d)) = (self.parts(), rhs.parts());
+
+          let denominator = c.mul(&c, config).add(&d.mul(&d, config), config);
+
+          Self::complex(
+            a.mul(&c, config)
+              .add(&b.mul(&d, config), config)
+              .div(&denominator, config)?,
+            b.mul(&c, config)
+              .sub(&a.mul(&d, config), config)
+              .div(&denominator, config)?,
+          )
+        }
+        (Self::Interval(_), _) | (_, Self::Interval(_)) => {
+          Self::Interval(Box::new(
+            self
+              .to_interval(config)
+              .div(&rhs.to_interval(config), config.precision())
+              .ok_or_else(|| {
+                Error::Message(
+                  "Cannot divide by an interval containing zero".into(),
+                )
+              })?,
+          ))
+        }
         (Self::Approx(lhs), Self::Approx(rhs)) => Self::Approx(
           Float::with_val_round(
             config.precision(),
@@ -168,6 +393,26 @@ impl Number {
     }
   }
 
+  fn domain(
+    &self,
+    config: Config,
+    name: &str,
+    interval: impl FnOnce(&Interval, u32) -> Interval,
+  ) -> std::result::Result<(), Error> {
+    match self {
+      Self::Interval(number)
+        if !number.is_point()
+          && !number.is_nan()
+          && interval(number, config.precision()).is_nan() =>
+      {
+        Err(Error::Message(format!(
+          "{name} is undefined on part of interval {self}"
+        )))
+      }
+      _ => Ok(()),
+    }
+  }
+
   #[must_use]
   pub fn e(config: Config) -> Self {
     Self::from(1_i64).exp(config)
@@ -175,14 +420,117 @@ impl Number {
 
   #[must_use]
   pub fn exp(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::exp_round)
+    self.approx_unary(
+      config,
+      Interval::exp,
+      Float::exp_round,
+      Complex::exp_round,
+    )
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `self` is a negative integer, an exact integer
+  /// too large to compute, a complex number, or an interval reaching below
+  /// one.
+  pub fn factorial(&self, config: Config) -> std::result::Result<Self, Error> {
+    if self.is_complex() {
+      return Err(Error::Message(format!(
+        "Factorial is undefined for complex number {self}"
+      )));
+    }
+
+    if let Some(integer) = self.to_integer() {
+      if integer.is_negative() {
+        return Err(Error::Message(format!(
+          "Factorial is undefined for negative integer {integer}"
+        )));
+      }
+
+      if let Self::Exact(_) = self {
+        let n = integer
+          .to_u32()
+          .filter(|n| *n <= MAX_FACTORIAL)
+          .ok_or_else(|| {
+            Error::Message(format!("Factorial of {integer} is too large"))
+          })?;
+
+        return Ok(Self::Exact(Rational::from(
+          Integer::factorial(n).complete(),
+        )));
+      }
+    }
+
+    if let Self::Interval(number) = self {
+      return number
+        .factorial(config.precision())
+        .map(|factorial| Self::Interval(Box::new(factorial)))
+        .ok_or_else(|| {
+          Error::Message(format!(
+            "Factorial of interval {self} needs a lower bound of at least 1"
+          ))
+        });
+    }
+
+    let mut number = self.add(&Self::from(1_i64), config).to_float(config);
+    number.gamma_round(config.rounding_mode);
+    Ok(Self::Approx(number))
   }
 
   #[must_use]
   pub fn floor(&self) -> Self {
     match self {
       Self::Approx(number) => Self::Approx(number.clone().floor()),
+      Self::Complex(parts) => Self::complex(parts.0.floor(), parts.1.floor()),
       Self::Exact(number) => Self::Exact(number.clone().floor()),
+      Self::Interval(number) => Self::Interval(Box::new(number.floor())),
+    }
+  }
+
+  pub(crate) fn from_complex(number: Complex) -> Self {
+    let (re, im) = number.into_real_imag();
+    Self::complex(Self::Approx(re), Self::Approx(im))
+  }
+
+  #[must_use]
+  pub fn im(&self) -> Self {
+    self.parts().1
+  }
+
+  #[must_use]
+  pub fn interval(lower: &Self, upper: &Self, config: Config) -> Option<Self> {
+    if lower.is_complex() || upper.is_complex() {
+      return None;
+    }
+
+    Interval::new(
+      lower.to_interval(config).lower().clone(),
+      upper.to_interval(config).upper().clone(),
+    )
+    .map(|interval| Self::Interval(Box::new(interval)))
+  }
+
+  #[must_use]
+  pub fn is_complex(&self) -> bool {
+    matches!(self, Self::Complex(_))
+  }
+
+  #[must_use]
+  pub fn is_exact(&self) -> bool {
+    match self {
+      Self::Approx(_) | Self::Interval(_) => false,
+      Self::Complex(parts) => parts.0.is_exact() && parts.1.is_exact(),
+      Self::Exact(_) => true,
+    }
+  }
+
+  #[must_use]
+  pub fn is_nan(&self) -> bool {
+    match self {
+      Self::Approx(number) => number.is_nan(),
+      Self::Complex(parts) => parts.0.is_nan() || parts.1.is_nan(),
+      Self::Exact(_) => false,
+      Self::Interval(number) => number.is_nan(),
     }
   }
 
@@ -192,7 +540,9 @@ impl Number {
       Self::Approx(number) => {
         matches!(number.cmp0(), Some(std::cmp::Ordering::Less))
       }
+      Self::Complex(_) => false,
       Self::Exact(number) => number.is_negative(),
+      Self::Interval(number) => *number.upper() < 0,
     }
   }
 
@@ -200,28 +550,79 @@ impl Number {
   pub fn is_zero(&self) -> bool {
     match self {
       Self::Approx(number) => number.is_zero(),
+      Self::Complex(_) => false,
       Self::Exact(number) => number.is_zero(),
+      Self::Interval(number) => number.is_point() && number.lower().is_zero(),
     }
   }
 
-  #[must_use]
-  pub fn ln(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::ln_round)
+  /// # Errors
+  ///
+  /// Returns an error if `self` is an interval that extends outside the
+  /// domain of the logarithm.
+  pub fn ln(&self, config: Config) -> std::result::Result<Self, Error> {
+    self.domain(config, "Logarithm", Interval::ln)?;
+
+    Ok(self.approx_unary(
+      config,
+      Interval::ln,
+      Float::ln_round,
+      Complex::ln_round,
+    ))
   }
 
-  #[must_use]
-  pub fn log10(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::log10_round)
+  /// # Errors
+  ///
+  /// Returns an error if `self` is an interval that extends outside the
+  /// domain of the logarithm.
+  pub fn log10(&self, config: Config) -> std::result::Result<Self, Error> {
+    self.domain(config, "Logarithm", Interval::log10)?;
+
+    Ok(self.approx_unary(
+      config,
+      Interval::log10,
+      Float::log10_round,
+      Complex::log10_round,
+    ))
   }
 
-  #[must_use]
-  pub fn log2(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::log2_round)
+  /// # Errors
+  ///
+  /// Returns an error if `self` is an interval that extends outside the
+  /// domain of the logarithm.
+  pub fn log2(&self, config: Config) -> std::result::Result<Self, Error> {
+    self.domain(config, "Logarithm", Interval::log2)?;
+
+    Ok(self.approx_unary(
+      config,
+      Interval::log2,
+      Float::log2_round,
+      |number, round| {
+        let ordering = number.ln_round(round);
+        *number /= Float::with_val(number.prec().0, Constant::Log2);
+        ordering
+      },
+    ))
   }
 
   #[must_use]
   pub fn mul(&self, rhs: &Self, config: Config) -> Self {
     match (self, rhs) {
+      (Self::Complex(_), _) | (_, Self::Complex(_)) => {
+        let ((a, b), (c, d)) = (self.parts(), rhs.parts());
+
+        Self::complex(
+          a.mul(&c, config).sub(&b.mul(&d, config), config),
+          a.mul(&d, config).add(&b.mul(&c, config), config),
+        )
+      }
+      (Self::Interval(_), _) | (_, Self::Interval(_)) => {
+        Self::Interval(Box::new(
+          self
+            .to_interval(config)
+            .mul(&rhs.to_interval(config), config.precision()),
+        ))
+      }
       (Self::Approx(lhs), Self::Approx(rhs)) => Self::Approx(
         Float::with_val_round(
           config.precision(),
@@ -249,10 +650,35 @@ impl Number {
   pub fn neg(&self) -> Self {
     match self {
       Self::Approx(number) => Self::Approx(-number.clone()),
+      Self::Complex(parts) => Self::complex(parts.0.neg(), parts.1.neg()),
       Self::Exact(number) => Self::Exact(-number.clone()),
+      Self::Interval(number) => Self::Interval(Box::new(number.neg())),
+    }
+  }
+
+  fn parts(&self) -> (Self, Self) {
+    match self {
+      Self::Complex(parts) => parts.as_ref().clone(),
+      number => (number.clone(), Self::from(0_i64)),
     }
   }
 
+  #[must_use]
+  pub fn pi(config: Config) -> Self {
+    if config.interval {
+      return Self::Interval(Box::new(Interval::pi(config.precision())));
+    }
+
+    Self::Approx(
+      Float::with_val_round(
+        config.precision(),
+        Constant::Pi,
+        config.rounding_mode,
+      )
+      .0,
+    )
+  }
+
   /// # Errors
   ///
   /// Returns [`Error::ZeroToNegativePower`] if `self` is zero and
@@ -267,12 +693,66 @@ impl Number {
     }
 
     match (self, rhs) {
+      (Self::Complex(_), Self::Exact(exponent)) if exponent.is_integer() => {
+        let Some(exponent) = exponent.numer().to_i32() else {
+          return Ok(self.approx_pow(rhs, config));
+        };
+
+        let (mut result, mut base) = (Self::from(1_i64), self.clone());
+
+        let mut remaining = exponent.unsigned_abs();
+
+        while remaining > 0 {
+          if remaining & 1 == 1 {
+            result = result.mul(&base, config);
+          }
+
+          base = base.mul(&base, config);
+          remaining >>= 1;
+        }
+
+        if exponent < 0 {
+          Self::from(1_i64).div(&result, config)
+        } else {
+          Ok(result)
+        }
+      }
+      (Self::Interval(_), _) | (_, Self::Interval(_))
+        if !self.is_complex() && !rhs.is_complex() =>
+      {
+        let Some(exponent) = rhs.to_integer() else {
+          return Ok(self.approx_pow(
+            rhs,
+            Config {
+              interval: true,
+              ..config
+            },
+          ));
+        };
+
+        let power = Self::Interval(Box::new(
+          self
+            .to_interval(config)
+            .pow(&exponent.clone().abs(), config.precision()),
+        ));
+
+        if exponent.is_negative() {
+          Self::from(1_i64).div(&power, config)
+        } else {
+          Ok(power)
+        }
+      }
       (Self::Exact(lhs), Self::Exact(exponent)) => {
         if exponent.is_integer() {
           if let Some(exponent) = exponent.numer().to_i32() {
             return Ok(Self::Exact(lhs.clone().pow(exponent)));
           }
 
+          if config.interval {
+            return Self::Interval(Box::new(self.to_interval(config)))
+              .pow(rhs, config);
+          }
+
           return Ok(Self::Approx(
             Float::with_val_round(
               config.precision(),
@@ -289,9 +769,15 @@ impl Number {
     }
   }
 
+  #[must_use]
+  pub fn re(&self) -> Self {
+    self.parts().0
+  }
+
   /// # Errors
   ///
-  /// Returns [`Error::ModuloByZero`] if `rhs` is zero.
+  /// Returns [`Error::ModuloByZero`] if `rhs` is zero, or an error if either
+  /// operand is complex or an interval.
   pub fn rem(
     &self,
     rhs: &Self,
@@ -301,6 +787,18 @@ impl Number {
       return Err(Error::ModuloByZero);
     }
 
+    if self.is_complex() || rhs.is_complex() {
+      return Err(Error::Message(
+        "Cannot take the remainder of a complex number".into(),
+      ));
+    }
+
+    if matches!(self, Self::Interval(_)) || matches!(rhs, Self::Interval(_)) {
+      return Err(Error::Message(
+        "Cannot take the remainder of an interval".into(),
+      ));
+    }
+
     match (self, rhs) {
       (Self::Exact(lhs), Self::Exact(rhs)) => {
         Ok(Self::Exact((lhs / rhs).complete().rem_floor() * rhs))
@@ -392,17 +890,67 @@ impl Number {
 
   #[must_use]
   pub fn sin(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::sin_round)
+    self.approx_unary(
+      config,
+      Interval::sin,
+      Float::sin_round,
+      Complex::sin_round,
+    )
   }
 
   #[must_use]
   pub fn sinh(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::sinh_round)
+    self.approx_unary(
+      config,
+      Interval::sinh,
+      Float::sinh_round,
+      Complex::sinh_round,
+    )
   }
 
-  #[must_use]
-  pub fn sqrt(&self, config: Config) -> Self {
+  /// # Errors
+  ///
+  /// Returns an error if `self` is an interval that extends below zero.
+  pub fn sqrt(&self, config: Config) -> std::result::Result<Self, Error> {
+    self.domain(config, "Square root", Interval::sqrt)?;
+
+    Ok(self.square_root(config))
+  }
+
+  fn square_root(&self, config: Config) -> Self {
     match self {
+      Self::Complex(parts) => {
+        let (re, im) = parts.as_ref();
+
+        if let Self::Exact(magnitude) = self.abs(config) {
+          let magnitude = Self::Exact(magnitude);
+
+          let half = |number: Self| {
+            number
+              .div(&Self::from(2_i64), config)
+              .map(|half| half.square_root(config))
+          };
+
+          if let (Ok(Self::Exact(a)), Ok(Self::Exact(b))) = (
+            half(magnitude.add(re, config)),
+            half(magnitude.sub(re, config)),
+          ) {
+            let b = if im.is_negative() { -b } else { b };
+            return Self::complex(Self::Exact(a), Self::Exact(b));
+          }
+        }
+
+        self.approx_unary(
+          config,
+          Interval::sqrt,
+          Float::sqrt_round,
+          Complex::sqrt_round,
+        )
+      }
+      Self::Exact(number) if number.is_negative() => Self::complex(
+        Self::from(0_i64),
+        Self::Exact(-number.clone()).square_root(config),
+      ),
       Self::Exact(number) => {
         if number.numer().is_perfect_square()
           && number.denom().is_perfect_square()
@@ -413,15 +961,36 @@ impl Number {
           )));
         }
 
-        self.approx_unary(config, Float::sqrt_round)
+        self.approx_unary(
+          config,
+          Interval::sqrt,
+          Float::sqrt_round,
+          Complex::sqrt_round,
+        )
       }
-      Self::Approx(_) => self.approx_unary(config, Float::sqrt_round),
+      Self::Approx(_) | Self::Interval(_) => self.approx_unary(
+        config,
+        Interval::sqrt,
+        Float::sqrt_round,
+        Complex::sqrt_round,
+      ),
     }
   }
 
   #[must_use]
   pub fn sub(&self, rhs: &Self, config: Config) -> Self {
     match (self, rhs) {
+      (Self::Complex(_), _) | (_, Self::Complex(_)) => {
+        let ((a, b), (c, d)) = (self.parts(), rhs.parts());
+        Self::complex(a.sub(&c, config), b.sub(&d, config))
+      }
+      (Self::Interval(_), _) | (_, Self::Interval(_)) => {
+        Self::Interval(Box::new(
+          self
+            .to_interval(config)
+            .sub(&rhs.to_interval(config), config.precision()),
+        ))
+      }
       (Self::Approx(lhs), Self::Approx(rhs)) => Self::Approx(
         Float::with_val_round(
           config.precision(),
@@ -454,30 +1023,45 @@ impl Number {
 
   #[must_use]
   pub fn tan(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::tan_round)
+    self.approx_unary(
+      config,
+      Interval::tan,
+      Float::tan_round,
+      Complex::tan_round,
+    )
   }
 
   #[must_use]
   pub fn tanh(&self, config: Config) -> Self {
-    self.approx_unary(config, Float::tanh_round)
+    self.approx_unary(
+      config,
+      Interval::tanh,
+      Float::tanh_round,
+      Complex::tanh_round,
+    )
   }
 
   #[must_use]
   pub fn tau(config: Config) -> Self {
-    Self::Approx(
-      Float::with_val_round(
-        config.precision(),
-        Constant::Pi,
-        config.rounding_mode,
-      )
-      .0,
-    )
-    .mul(&Self::from(2_i64), config)
+    Self::pi(config).mul(&Self::from(2_i64), config)
   }
 
   #[must_use]
   pub fn to_approx(&self, config: Config) -> Self {
-    Self::Approx(self.to_float(config))
+    match self {
+      Self::Complex(parts) => {
+        Self::complex(parts.0.to_approx(config), parts.1.to_approx(config))
+      }
+      number => Self::Approx(number.to_float(config)),
+    }
+  }
+
+  pub(crate) fn to_complex(&self, config: Config) -> Complex {
+    let (re, im) = self.parts();
+    Complex::with_val(
+      config.precision(),
+      (re.to_float(config), im.to_float(config)),
+    )
   }
 
   #[must_use]
@@ -487,10 +1071,21 @@ impl Number {
         Float::with_val_round(config.precision(), number, config.rounding_mode)
           .0
       }
+      Self::Complex(_) => {
+        Float::with_val(config.precision(), rug::float::Special::Nan)
+      }
       Self::Exact(number) => {
         Float::with_val_round(config.precision(), number, config.rounding_mode)
           .0
       }
+      Self::Interval(number) => {
+        Float::with_val_round(
+          config.precision(),
+          number.midpoint(config.precision()),
+          config.rounding_mode,
+        )
+        .0
+      }
     }
   }
 
@@ -502,6 +1097,10 @@ impl Number {
   #[must_use]
   pub fn to_integer(&self) -> Option<Integer> {
     match self {
+      Self::Interval(number) if number.is_point() => {
+        Self::Approx(number.lower().clone()).to_integer()
+      }
+      Self::Complex(_) | Self::Interval(_) => None,
       Self::Exact(number) => {
         if number.is_integer() {
           Some(number.numer().clone())
@@ -519,6 +1118,15 @@ impl Number {
     }
   }
 
+  fn to_interval(&self, config: Config) -> Interval {
+    match self {
+      Self::Approx(number) => Interval::point(number.clone()),
+      Self::Complex(_) => Interval::point(self.to_float(config)),
+      Self::Exact(number) => Interval::enclose(number, config.precision()),
+      Self::Interval(number) => number.as_ref().clone(),
+    }
+  }
+
   #[must_use]
   pub fn to_non_negative_usize(&self) -> Option<usize> {
     match self {
@@ -531,8 +1139,8 @@ impl Number {
           number.to_usize()
         }
       }
-      Self::Exact(_) => None,
-      Self::Approx(_) => {
+      Self::Complex(_) | Self::Exact(_) => None,
+      Self::Approx(_) | Self::Interval(_) => {
         let number = self.to_integer()?;
 
         if number.is_negative() {
@@ -551,6 +1159,8 @@ impl Display for Number {
   }
 }
 
+impl Eq for Number {}
+
 impl From<bool> for Number {
   fn from(value: bool) -> Self {
     Self::from(i64::from(value))
@@ -575,6 +1185,29 @@ impl From<usize> for Number {
   }
 }
 
+impl Hash for Number {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self {
+      Self::Approx(number) => match number.to_rational() {
+        Some(number) => number.hash(state),
+        None => number.is_sign_negative().hash(state),
+      },
+      Self::Complex(parts) => {
+        parts.0.hash(state);
+        parts.1.hash(state);
+      }
+      Self::Exact(number) => number.hash(state),
+      Self::Interval(number) if number.is_point() => {
+        Self::Approx(number.lower().clone()).hash(state);
+      }
+      Self::Interval(number) => {
+        Self::Approx(number.lower().clone()).hash(state);
+        Self::Approx(number.upper().clone()).hash(state);
+      }
+    }
+  }
+}
+
 impl PartialEq for Number {
   fn eq(&self, other: &Self) -> bool {
     self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
@@ -584,6 +1217,34 @@ impl PartialEq for Number {
 impl PartialOrd for Number {
   fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
     match (self, other) {
+      (Self::Complex(_), _) | (_, Self::Complex(_)) => {
+        (self.parts() == other.parts()).then_some(std::cmp::Ordering::Equal)
+      }
+      (Self::Interval(_), _) | (_, Self::Interval(_)) => {
+        let config = Config {
+          precision: [self, other]
+            .into_iter()
+            .filter_map(|number| match number {
+              Self::Interval(number) => Some(number.lower().prec()),
+              _ => None,
+            })
+            .max()
+            .unwrap_or_default(),
+          ..Config::default()
+        };
+
+        let (lhs, rhs) = (self.to_interval(config), other.to_interval(config));
+
+        if lhs.upper() < rhs.lower() {
+          Some(std::cmp::Ordering::Less)
+        } else if lhs.lower() > rhs.upper() {
+          Some(std::cmp::Ordering::Greater)
+        } else if lhs.lower() == rhs.lower() && lhs.upper() == rhs.upper() {
+          Some(std::cmp::Ordering::Equal)
+        } else {
+          None
+        }
+      }
       (Self::Exact(lhs), Self::Exact(rhs)) => lhs.partial_cmp(rhs),
       (Self::Approx(lhs), Self::Approx(rhs)) => lhs.partial_cmp(rhs),
       (Self::Exact(lhs), Self::Approx(rhs)) => lhs.partial_cmp(rhs),
@@ -596,6 +1257,28 @@ impl TryFrom<&str> for Number {
   type Error = Error;
 
   fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+    fn digits(s: &str, radix: u32) -> Option<String> {
+      let mut digits = String::with_capacity(s.len());
+
+      let mut previous = None;
+
+      for character in s.chars() {
+        if character == '_' {
+          if !previous.is_some_and(|previous: char| previous.is_digit(radix)) {
+            return None;
+          }
+        } else if character.is_digit(radix) {
+          digits.push(character);
+        } else {
+          return None;
+        }
+
+        previous = Some(character);
+      }
+
+      (previous != Some('_')).then_some(digits)
+    }
+
     let s = s.trim();
 
     let (negative, s) = s
@@ -604,34 +1287,86 @@ impl TryFrom<&str> for Number {
       .or_else(|| s.strip_prefix('+').map(|s| (false, s)))
       .unwrap_or((false, s));
 
-    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    let radix = match s.get(..2) {
+      Some("0b" | "0B") => Some(2_i32),
+      Some("0o" | "0O") => Some(8),
+      Some("0x" | "0X") => Some(16),
+      _ => None,
+    };
 
-    if integer.is_empty() && fraction.is_empty() {
-      return Err(Error::InvalidDecimal);
-    }
+    let mut number = if let Some(radix) = radix {
+      let digits = digits(&s[2..], radix.unsigned_abs())
+        .filter(|digits| !digits.is_empty())
+        .ok_or(Error::InvalidDecimal)?;
 
-    let mut numerator = Integer::from(0);
+      Rational::from(
+        Integer::from_str_radix(&digits, radix)
+          .map_err(|_| Error::InvalidDecimal)?,
+      )
+    } else {
+      let (mantissa, exponent) = s
+        .split_once(['e', 'E'])
+        .map_or((s, None), |(mantissa, exponent)| (mantissa, Some(exponent)));
+
+      let (integer, fraction) =
+        mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+      let integer = digits(integer, 10).ok_or(Error::InvalidDecimal)?;
 
-    for b in integer.bytes().chain(fraction.bytes()) {
-      if !b.is_ascii_digit() {
+      let fraction = digits(fraction, 10).ok_or(Error::InvalidDecimal)?;
+
+      if integer.is_empty() && fraction.is_empty() {
         return Err(Error::InvalidDecimal);
       }
 
-      numerator *= 10;
-      numerator += b - b'0';
-    }
+      let exponent = match exponent {
+        Some(exponent) => {
+          let (negative, exponent) = exponent
+            .strip_prefix('-')
+            .map(|exponent| (true, exponent))
+            .or_else(|| exponent.strip_prefix('+').map(|e| (false, e)))
+            .unwrap_or((false, exponent));
+
+          let exponent = digits(exponent, 10)
+            .filter(|exponent| !exponent.is_empty())
+            .and_then(|exponent| exponent.parse::<i64>().ok())
+            .ok_or(Error::InvalidDecimal)?;
+
+          if negative { -exponent } else { exponent }
+        }
+        None => 0,
+      };
+
+      let exponent = exponent
+        .checked_sub(
+          i64::try_from(fraction.len()).map_err(|_| Error::InvalidDecimal)?,
+        )
+        .ok_or(Error::InvalidDecimal)?;
+
+      let numerator = Integer::from_str_radix(&(integer + &fraction), 10)
+        .map_err(|_| Error::InvalidDecimal)?;
+
+      if exponent.unsigned_abs() > MAX_EXPONENT {
+        return Err(Error::ExponentTooLarge);
+      }
+
+      let scale = Integer::from(10).pow(
+        u32::try_from(exponent.unsigned_abs())
+          .map_err(|_| Error::InvalidDecimal)?,
+      );
+
+      if exponent < 0 {
+        Rational::from((numerator, scale))
+      } else {
+        Rational::from(numerator * scale)
+      }
+    };
 
     if negative {
-      numerator = -numerator;
+      number = -number;
     }
 
-    let exponent =
-      u32::try_from(fraction.len()).map_err(|_| Error::InvalidDecimal)?;
-
-    Ok(Self::Exact(Rational::from((
-      numerator,
-      Integer::from(10).pow(exponent),
-    ))))
+    Ok(Self::Exact(number))
   }
 }
 
@@ -679,6 +1414,38 @@ mod tests {
     );
   }
 
+  #[test]
+  fn complex_exact_arithmetic() {
+    let config = Config::default();
+
+    let exact =
+      |re: i64, im: i64| Number::complex(Number::from(re), Number::from(im));
+
+    assert_eq!(exact(1, 2).mul(&exact(3, -1), config), exact(5, 5));
+
+    assert_eq!(
+      exact(1, 2).div(&exact(3, -4), config),
+      Ok(Number::complex(
+        Number::Exact(Rational::from((-1, 5))),
+        Number::Exact(Rational::from((2, 5))),
+      ))
+    );
+
+    assert_eq!(exact(0, 1).mul(&exact(0, 1), config), Number::from(-1_i64));
+    assert_eq!(Number::from(-4_i64).sqrt(config), Ok(exact(0, 2)));
+    assert_eq!(exact(3, 4).sqrt(config), Ok(exact(2, 1)));
+    assert_eq!(exact(3, 4).abs(config), Number::from(5_i64));
+    assert_eq!(exact(3, 4).conj(), exact(3, -4));
+
+    assert_eq!(
+      exact(1, 1).pow(&Number::from(-2_i64), config),
+      Ok(Number::complex(
+        Number::from(0_i64),
+        Number::Exact(Rational::from((-1, 2))),
+      ))
+    );
+  }
+
   #[test]
   fn display_approx_configured_digits() {
     let config = Config {
@@ -751,13 +1518,127 @@ mod tests {
     );
   }
 
+  #[test]
+  fn display_complex_infinity() {
+    let config = Config::default();
+
+    let infinity = Number::Approx(Float::with_val(8, Special::Infinity));
+
+    assert_eq!(
+      Number::complex(Number::from(0_i64), infinity.clone()).display(config),
+      "inf*i"
+    );
+
+    assert_eq!(
+      Number::complex(Number::from(1_i64), infinity.neg()).display(config),
+      "1-inf*i"
+    );
+  }
+
+  #[test]
+  fn display_interval() {
+    let config = Config {
+      precision: 53,
+      ..Config::default()
+    };
+
+    assert_eq!(
+      Number::interval(&Number::from(1_i64), &Number::from(2_i64), config)
+        .unwrap()
+        .display(config),
+      "1.5 ± 0.5"
+    );
+
+    assert_eq!(
+      Number::interval(&Number::from(3_i64), &Number::from(3_i64), config)
+        .unwrap()
+        .display(config),
+      "3 ± 0"
+    );
+
+    assert_eq!(
+      Number::Interval(Box::new(Interval::pi(53))).display(config),
+      "3.141592653589793 ± 2.3e-16"
+    );
+
+    assert_eq!(
+      Number::interval(&Number::from(1_i64), &Number::from(2_i64), config)
+        .unwrap()
+        .tan(config)
+        .display(config),
+      "[-inf, inf]"
+    );
+  }
+
+  #[test]
+  fn hash_agrees_with_equality() {
+    #[track_caller]
+    fn case(lhs: &Number, rhs: &Number) {
+      use std::hash::{BuildHasher, RandomState};
+
+      let state = RandomState::new();
+
+      assert_eq!(lhs, rhs);
+      assert_eq!(state.hash_one(lhs), state.hash_one(rhs));
+    }
+
+    case(
+      &Number::from(1_i64),
+      &Number::Approx(Float::with_val(53, 1)),
+    );
+
+    case(
+      &Number::Exact(Rational::from((1, 2))),
+      &Number::Approx(Float::with_val(53, 0.5)),
+    );
+
+    case(
+      &Number::from(0_i64),
+      &Number::Approx(Float::with_val(53, -0.0)),
+    );
+  }
+
   #[test]
   fn invalid_decimal_returns_error() {
-    for value in [".", "foo"] {
+    for value in [
+      ".", "foo", "0x", "0xg", "0b102", "1__0", "_1", "1_", "1e", "1e+",
+      "1.5e", "1_.5", "e5",
+    ] {
       assert_eq!(Number::try_from(value), Err(Error::InvalidDecimal));
     }
   }
 
+  #[test]
+  fn large_exponent_returns_error() {
+    for value in ["1e1000001", "1e-1000001", "1e4000000000", "1.5e-1000000"] {
+      assert_eq!(Number::try_from(value), Err(Error::ExponentTooLarge));
+    }
+
+    assert!(Number::try_from("1e1000000").is_ok());
+  }
+
+  #[test]
+  fn literal_forms() {
+    #[track_caller]
+    fn case(value: &str, expected: &str) {
+      assert_eq!(
+        Number::try_from(value),
+        Ok(Number::Exact(expected.parse::<Rational>().unwrap()))
+      );
+    }
+
+    case("0xff", "255");
+    case("0XFF", "255");
+    case("-0x1_0", "-16");
+    case("0b1010", "10");
+    case("0o17", "15");
+    case("1_000_000", "1000000");
+    case("6.022e23", "602200000000000000000000");
+    case("1e-30", "1/1000000000000000000000000000000");
+    case("2.5E+2", "250");
+    case("1_0.2_5", "41/4");
+  }
+
   #[test]
   fn list_indexes_integer() {
     assert_eq!(
@@ -0,0 +1,49 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Overflow {
+  Checked,
+  Saturating,
+  #[default]
+  Wrapping,
+}
+
+impl Overflow {
+  #[must_use]
+  pub fn strictest(self, other: Self) -> Self {
+    match (self, other) {
+      (Overflow::Checked, _) | (_, Overflow::Checked) => Overflow::Checked,
+      (Overflow::Saturating, _) | (_, Overflow::Saturating) => {
+        Overflow::Saturating
+      }
+      (Overflow::Wrapping, Overflow::Wrapping) => Overflow::Wrapping,
+    }
+  }
+}
+
+impl Display for Overflow {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Overflow::Checked => "checked",
+        Overflow::Saturating => "saturating",
+        Overflow::Wrapping => "wrapping",
+      }
+    )
+  }
+}
+
+impl FromStr for Overflow {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "checked" => Ok(Overflow::Checked),
+      "saturating" => Ok(Overflow::Saturating),
+      "wrapping" => Ok(Overflow::Wrapping),
+      _ => Err(format!("Unknown overflow mode: {s}")),
+    }
+  }
+}
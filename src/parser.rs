@@ -1,21 +1,61 @@
 use {
   super::*,
-  chumsky::input::MapExtra,
+  chumsky::input::{MapExtra, WithContext},
   chumsky::pratt::{infix, left, postfix, prefix, right},
 };
 
-type ParserError<'a> = extra::Err<Rich<'a, char>>;
+type Arguments = (
+  Vec<Spanned<Expression>>,
+  Vec<(Spanned<String>, Spanned<Expression>)>,
+);
 
-const RESERVED_WORDS: [&str; 13] = [
-  "break", "continue", "else", "false", "fn", "for", "if", "in", "loop",
-  "null", "return", "true", "while",
+type ParserError<'a> = extra::Err<Rich<'a, char, Span>>;
+
+type ParserInput<'a> = WithContext<Span, &'a str>;
+
+const RESERVED_WORDS: [&str; 21] = [
+  "break", "catch", "const", "continue", "else", "false", "finally", "fn",
+  "for", "if", "import", "in", "let", "loop", "match", "null", "return",
+  "throw", "true", "try", "while",
+];
+
+const TYPE_NAMES: [&str; 19] = [
+  "boolean",
+  "error",
+  "function",
+  "i16",
+  "i32",
+  "i64",
+  "i8",
+  "list",
+  "map",
+  "matrix",
+  "null",
+  "number",
+  "polynomial",
+  "range",
+  "string",
+  "u16",
+  "u32",
+  "u64",
+  "u8",
 ];
 
 /// # Errors
 ///
 /// Returns parser errors when input cannot be parsed into a complete program.
 pub fn parse(input: &str) -> Result<Spanned<Program>, Vec<Error>> {
-  let result = program_parser().parse(input);
+  parse_with_source(input, SourceId::default())
+}
+
+/// # Errors
+///
+/// Returns parser errors when input cannot be parsed into a complete program.
+pub fn parse_with_source(
+  input: &str,
+  source: SourceId,
+) -> Result<Spanned<Program>, Vec<Error>> {
+  let result = program_parser().parse(input.with_context(source));
 
   match result.into_output_errors() {
     (Some(ast), errors) if errors.is_empty() => Ok(ast),
@@ -29,7 +69,7 @@ pub fn parse(input: &str) -> Result<Spanned<Program>, Vec<Error>> {
 }
 
 fn program_parser<'a>()
--> impl Parser<'a, &'a str, Spanned<Program>, ParserError<'a>> + Clone {
+-> impl Parser<'a, ParserInput<'a>, Spanned<Program>, ParserError<'a>> + Clone {
   padding_parser()
     .ignore_then(statement_list_parser(statement_parser()))
     .then_ignore(padding_parser())
@@ -37,11 +77,141 @@ fn program_parser<'a>()
     .map_with(|ast, error| (ast, error.span()))
 }
 
+fn absolute_value_parser<'a, P>(
+  expression: P,
+) -> impl Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  just('|')
+    .ignore_then(expression)
+    .then_ignore(just('|'))
+    .validate(|inner, extra, emitter| {
+      if let Expression::BinaryOp(BinaryOp::BitwiseOr, _, _) = inner.0 {
+        emitter.emit(Rich::custom(
+          inner.1,
+          "Ambiguous `|` inside absolute value bars, wrap it in parentheses",
+        ));
+      }
+
+      (
+        Expression::UnaryOp(UnaryOp::Abs, Box::new(inner)),
+        extra.span(),
+      )
+    })
+}
+
+fn chain_comparison<'a>(
+  lhs: Spanned<Expression>,
+  op: BinaryOp,
+  rhs: Spanned<Expression>,
+  extra: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>,
+) -> Spanned<Expression> {
+  let direction = |op: &BinaryOp| match op {
+    BinaryOp::LessThan | BinaryOp::LessThanEqual => Some(Ordering::Less),
+    BinaryOp::GreaterThan | BinaryOp::GreaterThanEqual => {
+      Some(Ordering::Greater)
+    }
+    _ => None,
+  };
+
+  let span = extra.span();
+
+  let (first, mut rest) = match lhs {
+    _ if direction(&op).is_none() || !is_unparenthesized(&lhs, extra) => {
+      return (Expression::BinaryOp(op, Box::new(lhs), Box::new(rhs)), span);
+    }
+    (Expression::BinaryOp(previous, first, second), _)
+      if direction(&previous).is_some() =>
+    {
+      (first, vec![(previous, *second)])
+    }
+    (Expression::Comparison(first, rest), _) => (first, rest),
+    lhs => {
+      return (Expression::BinaryOp(op, Box::new(lhs), Box::new(rhs)), span);
+    }
+  };
+
+  if direction(&rest[0].0) != direction(&op) {
+    extra.emit(Rich::custom(
+      span,
+      format!(
+        "Ambiguous comparison chain mixing `{}` and `{op}`, split it with `&&`",
+        rest[0].0
+      ),
+    ));
+  }
+
+  rest.push((op, rhs));
+
+  (Expression::Comparison(first, rest), span)
+}
+
+fn coefficient_parser<'a, P>(
+  expression: P,
+) -> impl Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  let term = choice((
+    unpadded_identifier_parser()
+      .map(Expression::Identifier)
+      .map_with(|ast, error| (ast, error.span())),
+    expression.delimited_by(just('('), just(')')),
+  ));
+
+  number_parser()
+    .map(Expression::Number)
+    .map_with(|ast, error| (ast, error.span()))
+    .then(term.or_not())
+    .map_with(|(coefficient, term), error| match term {
+      Some(term) => (
+        Expression::BinaryOp(
+          BinaryOp::Multiply,
+          Box::new(coefficient),
+          Box::new(term),
+        ),
+        error.span(),
+      ),
+      None => coefficient,
+    })
+}
+
+fn superscript_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone
+{
+  const DIGITS: &str = "⁰¹²³⁴⁵⁶⁷⁸⁹";
+
+  let digit = one_of(DIGITS).map(|digit| {
+    DIGITS
+      .chars()
+      .position(|candidate| candidate == digit)
+      .unwrap_or_default()
+  });
+
+  just('⁻')
+    .or_not()
+    .then(digit.repeated().at_least(1).collect::<Vec<usize>>())
+    .map(|(sign, digits)| {
+      let exponent = digits
+        .into_iter()
+        .fold(Integer::new(), |exponent, digit| exponent * 10 + digit);
+
+      Expression::Number(Number::from(if sign.is_some() {
+        -exponent
+      } else {
+        exponent
+      }))
+    })
+    .map_with(|ast, error| (ast, error.span()))
+    .labelled("superscript exponent")
+}
+
 fn comma_separated_parser<'a, P, T>(
   parser: P,
-) -> impl Parser<'a, &'a str, Vec<T>, ParserError<'a>> + Clone
+) -> impl Parser<'a, ParserInput<'a>, Vec<T>, ParserError<'a>> + Clone
 where
-  P: Parser<'a, &'a str, T, ParserError<'a>> + Clone,
+  P: Parser<'a, ParserInput<'a>, T, ParserError<'a>> + Clone,
 {
   parser
     .separated_by(padded_parser(just(',')))
@@ -49,12 +219,113 @@ where
     .collect::<Vec<_>>()
 }
 
+fn comparison_operator_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, BinaryOp, ParserError<'a>> + Clone {
+  choice((
+    padded_parser(just(">=").or(just("≥"))).to(BinaryOp::GreaterThanEqual),
+    padded_parser(just("<=").or(just("≤"))).to(BinaryOp::LessThanEqual),
+    padded_parser(just('>').then(just('>').not())).to(BinaryOp::GreaterThan),
+    padded_parser(just('<').then(just('<').not())).to(BinaryOp::LessThan),
+    keyword_parser("in").to(BinaryOp::In),
+  ))
+}
+
+fn factorial_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, (), ParserError<'a>> + Clone {
+  same_line_parser()
+    .ignore_then(just('!'))
+    .then(just('=').not())
+    .ignored()
+}
+
+fn fold_factorial<'a>(
+  operand: Spanned<Expression>,
+  (): (),
+  extra: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>,
+) -> Spanned<Expression> {
+  if extra
+    .slice()
+    .trim_end_matches('!')
+    .ends_with(char::is_whitespace)
+  {
+    let span = extra.span();
+
+    extra.emit(Rich::custom(
+      span,
+      "Factorial `!` must directly follow its operand",
+    ));
+  }
+
+  implicit_operand(operand, extra, |operand| {
+    Expression::UnaryOp(UnaryOp::Factorial, Box::new(operand))
+  })
+}
+
+fn fold_function_call<'a>(
+  function: Spanned<Expression>,
+  ((arguments, named), _): (Arguments, Span),
+  extra: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>,
+) -> Spanned<Expression> {
+  if let Expression::Number(_) = function.0 {
+    let span = extra.span();
+
+    extra.emit(Rich::custom(
+      span,
+      "Cannot call a number, use `*` to multiply",
+    ));
+  }
+
+  implicit_operand(function, extra, |function| {
+    Expression::FunctionCall(Box::new(function), arguments, named)
+  })
+}
+
+fn fold_superscript<'a>(
+  base: Spanned<Expression>,
+  exponent: Spanned<Expression>,
+  extra: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>,
+) -> Spanned<Expression> {
+  implicit_operand(base, extra, |base| {
+    Expression::BinaryOp(BinaryOp::Power, Box::new(base), Box::new(exponent))
+  })
+}
+
+fn implicit_operand<'a>(
+  lhs: Spanned<Expression>,
+  extra: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>,
+  build: impl FnOnce(Spanned<Expression>) -> Expression,
+) -> Spanned<Expression> {
+  let span = extra.span();
+
+  match lhs {
+    (Expression::BinaryOp(BinaryOp::Multiply, coefficient, term), _)
+      if matches!(coefficient.0, Expression::Number(_))
+        && is_unparenthesized(&coefficient, extra) =>
+    {
+      let term_span = Span {
+        start: term.1.start,
+        ..span
+      };
+
+      (
+        Expression::BinaryOp(
+          BinaryOp::Multiply,
+          coefficient,
+          Box::new((build(*term), term_span)),
+        ),
+        span,
+      )
+    }
+    lhs => (build(lhs), span),
+  }
+}
+
 fn index_parser<'a, P>(
   expression: P,
-) -> impl Parser<'a, &'a str, (Spanned<Expression>, SimpleSpan), ParserError<'a>>
+) -> impl Parser<'a, ParserInput<'a>, (Spanned<Expression>, Span), ParserError<'a>>
 + Clone
 where
-  P: Parser<'a, &'a str, Spanned<Expression>, ParserError<'a>> + Clone,
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
 {
   expression
     .delimited_by(padded_parser(just('[')), padded_parser(just(']')))
@@ -62,37 +333,111 @@ where
     .map_with(|expression, error| (expression, error.span()))
 }
 
+fn slice_parser<'a, P>(
+  expression: P,
+) -> impl Parser<'a, ParserInput<'a>, (Slice, Span), ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  let bound = expression.map(Box::new).or_not();
+
+  bound
+    .clone()
+    .then_ignore(padded_parser(just(':')))
+    .then(bound.clone())
+    .then(padded_parser(just(':')).ignore_then(bound).or_not())
+    .delimited_by(padded_parser(just('[')), padded_parser(just(']')))
+    .padded_by(padding_parser())
+    .map_with(|((start, stop), step), error| {
+      let slice = Slice {
+        start,
+        step: step.flatten(),
+        stop,
+      };
+
+      (slice, error.span())
+    })
+}
+
 fn identifier_parser<'a>()
--> impl Parser<'a, &'a str, String, ParserError<'a>> + Clone {
-  padded_parser(text::ident().try_map(|identifier, span| {
-    if RESERVED_WORDS.contains(&identifier) {
-      Err(Rich::custom(
-        span,
-        format!("`{identifier}` is a reserved word"),
-      ))
-    } else {
-      Ok(identifier.to_owned())
-    }
-  }))
+-> impl Parser<'a, ParserInput<'a>, String, ParserError<'a>> + Clone {
+  padded_parser(unpadded_identifier_parser())
+}
+
+fn unpadded_identifier_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, String, ParserError<'a>> + Clone {
+  text::ident()
+    .try_map(|identifier, span| {
+      if RESERVED_WORDS.contains(&identifier) {
+        Err(Rich::custom(
+          span,
+          format!("`{identifier}` is a reserved word"),
+        ))
+      } else {
+        Ok(identifier.to_owned())
+      }
+    })
+    .then_ignore(one_of(['"', '\'']).not())
+}
+
+fn is_unparenthesized<'a>(
+  lhs: &Spanned<Expression>,
+  extra: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>,
+) -> bool {
+  let start = extra.span().start;
+
+  extra.slice()[..lhs.1.start - start].trim().is_empty()
+}
+
+fn interpolation_parser<'a, P>(
+  expression: P,
+  quote: char,
+) -> impl Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  let text = choice((
+    just("{{").to('{'),
+    just("}}").to('}'),
+    escape_parser(),
+    none_of([quote, '\\', '{', '}']),
+  ))
+  .repeated()
+  .at_least(1)
+  .collect::<String>()
+  .map(Expression::String)
+  .map_with(|ast, error| (ast, error.span()));
+
+  just('f')
+    .ignore_then(just(quote))
+    .ignore_then(
+      text
+        .or(expression.delimited_by(just('{'), just('}')))
+        .repeated()
+        .collect::<Vec<_>>(),
+    )
+    .then_ignore(just(quote))
+    .map(Expression::Interpolation)
+    .map_with(|ast, error| (ast, error.span()))
 }
 
 fn keyword_parser<'a>(
   keyword: &'static str,
-) -> impl Parser<'a, &'a str, (), ParserError<'a>> + Clone {
+) -> impl Parser<'a, ParserInput<'a>, (), ParserError<'a>> + Clone {
   padded_parser(text::keyword(keyword)).ignored()
 }
 
 fn padded_parser<'a, P, T>(
   parser: P,
-) -> impl Parser<'a, &'a str, T, ParserError<'a>> + Clone
+) -> impl Parser<'a, ParserInput<'a>, T, ParserError<'a>> + Clone
 where
-  P: Parser<'a, &'a str, T, ParserError<'a>> + Clone,
+  P: Parser<'a, ParserInput<'a>, T, ParserError<'a>> + Clone,
 {
   parser.padded_by(padding_parser())
 }
 
-fn padding_parser<'a>() -> impl Parser<'a, &'a str, (), ParserError<'a>> + Clone
-{
+fn padding_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, (), ParserError<'a>> + Clone {
   custom(|input| {
     loop {
       let checkpoint = input.save();
@@ -117,11 +462,335 @@ fn padding_parser<'a>() -> impl Parser<'a, &'a str, (), ParserError<'a>> + Clone
   })
 }
 
+fn same_line_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, (), ParserError<'a>> + Clone {
+  custom(|input| {
+    let cursor = input.cursor();
+    let span: Span = input.span_since(&cursor);
+    let source: &str = input.full_slice();
+    let preceding = &source[..span.start];
+
+    if preceding[preceding.trim_end().len()..].contains('\n') {
+      return Err(Rich::custom(span, "Expected operand on the same line"));
+    }
+
+    Ok(())
+  })
+}
+
+fn escape_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, char, ParserError<'a>> + Clone {
+  let unicode_escape = just("\\u{")
+    .ignore_then(none_of(['}', '"', '\'']).repeated().to_slice())
+    .then_ignore(just('}'))
+    .validate(|digits: &str, extra, emitter| {
+      u32::from_str_radix(digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .unwrap_or_else(|| {
+          emitter.emit(Rich::custom(
+            extra.span(),
+            format!("Invalid unicode escape `\\u{{{digits}}}`"),
+          ));
+
+          char::REPLACEMENT_CHARACTER
+        })
+    });
+
+  let escape =
+    just('\\')
+      .ignore_then(any())
+      .validate(|character, extra, emitter| match character {
+        '\\' | '"' | '\'' => character,
+        '0' => '\0',
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        _ => {
+          emitter.emit(Rich::custom(
+            extra.span(),
+            format!("Invalid escape sequence `\\{character}`"),
+          ));
+
+          character
+        }
+      });
+
+  unicode_escape.or(escape)
+}
+
+fn arguments_parser<'a, P>(
+  expression: P,
+) -> impl Parser<'a, ParserInput<'a>, (Arguments, Span), ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  let argument = padding_parser()
+    .ignore_then(
+      identifier_parser().map_with(|name, error| (name, error.span())),
+    )
+    .then_ignore(padded_parser(just(':')))
+    .or_not()
+    .then(expression);
+
+  comma_separated_parser(argument)
+    .delimited_by(padded_parser(just('(')), padded_parser(just(')')))
+    .validate(
+      |arguments: Vec<(Option<Spanned<String>>, Spanned<Expression>)>,
+       _,
+       emitter| {
+        let (mut positional, mut named) = (Vec::new(), Vec::new());
+
+        for (name, argument) in arguments {
+          match name {
+            Some(name) => named.push((name, argument)),
+            None if !named.is_empty() => emitter.emit(Rich::custom(
+              argument.1,
+              "Positional argument cannot follow named arguments",
+            )),
+            None => positional.push(argument),
+          }
+        }
+
+        (positional, named)
+      },
+    )
+    .padded_by(padding_parser())
+    .map_with(|arguments, error| (arguments, error.span()))
+}
+
+fn parameters_parser<'a, P>(
+  expression: P,
+) -> impl Parser<'a, ParserInput<'a>, Vec<Parameter>, ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  let parameter = choice((
+    identifier_parser()
+      .then_ignore(padded_parser(just('=')))
+      .then(expression)
+      .map(|(name, default)| Parameter::Default(name, default)),
+    identifier_parser().map(Parameter::Positional),
+    padded_parser(just("..."))
+      .ignore_then(identifier_parser())
+      .map(Parameter::Rest),
+  ))
+  .map_with(|parameter, error| (parameter, error.span()));
+
+  comma_separated_parser(parameter)
+    .delimited_by(padded_parser(just('(')), padded_parser(just(')')))
+    .validate(|parameters: Vec<(Parameter, Span)>, _, emitter| {
+      let mut defaulted = false;
+
+      for (index, (parameter, span)) in parameters.iter().enumerate() {
+        match parameter {
+          Parameter::Default(_, _) => defaulted = true,
+          Parameter::Positional(name) if defaulted => {
+            emitter.emit(Rich::custom(
+              *span,
+              format!(
+                "Required parameter `{name}` cannot follow a parameter with a default value"
+              ),
+            ));
+          }
+          Parameter::Rest(name) if index + 1 < parameters.len() => {
+            emitter.emit(Rich::custom(
+              *span,
+              format!("Rest parameter `...{name}` must be the last parameter"),
+            ));
+          }
+          _ => {}
+        }
+      }
+
+      parameters
+        .into_iter()
+        .map(|(parameter, _)| parameter)
+        .collect()
+    })
+}
+
+fn number_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, Number, ParserError<'a>> + Clone {
+  let digits = |radix: u32| {
+    text::digits(radix)
+      .then(just('_').then(text::digits(radix)).repeated())
+      .to_slice()
+  };
+
+  let prefixed = |prefix: char, radix: u32| {
+    just('0')
+      .then(one_of([prefix, prefix.to_ascii_uppercase()]))
+      .then(digits(radix))
+  };
+
+  let decimal = text::int(10)
+    .then(just('_').then(text::digits(10)).repeated())
+    .then(just('.').then(digits(10)).or_not())
+    .then(
+      one_of("eE")
+        .then(one_of("+-").or_not())
+        .then(digits(10))
+        .or_not(),
+    );
+
+  let word = any()
+    .filter(|character: &char| character.is_alphanumeric() || *character == '_')
+    .repeated();
+
+  let malformed = choice((
+    just('_').then(word).ignored(),
+    one_of("eE").then(one_of("+-")).ignored(),
+    one_of("eE").then(word.at_least(1).not()).ignored(),
+  ));
+
+  choice((
+    prefixed('x', 16).ignored(),
+    prefixed('b', 2).ignored(),
+    prefixed('o', 8).ignored(),
+    just('0').then(one_of("xXbBoO")).then(word).ignored(),
+    decimal.then(malformed.or_not()).ignored(),
+  ))
+  .to_slice()
+  .validate(|number: &str, extra, emitter| {
+    Number::try_from(number).unwrap_or_else(|error| {
+      emitter.emit(Rich::custom(
+        extra.span(),
+        match error {
+          Error::ExponentTooLarge => {
+            format!("Exponent too large in number literal `{number}`")
+          }
+          _ => format!("Invalid number literal `{number}`"),
+        },
+      ));
+
+      Number::from(0_i64)
+    })
+  })
+}
+
+fn match_parser<'a, P>(
+  expression: P,
+) -> impl Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone
+where
+  P: Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone,
+{
+  let arm = pattern_parser()
+    .then(
+      keyword_parser("if")
+        .ignore_then(expression.clone())
+        .or_not(),
+    )
+    .then_ignore(padded_parser(just("=>")))
+    .then(expression.clone())
+    .map(|((pattern, guard), body)| MatchArm {
+      body,
+      guard,
+      pattern,
+    });
+
+  keyword_parser("match")
+    .ignore_then(
+      expression
+        .delimited_by(padded_parser(just('(')), padded_parser(just(')'))),
+    )
+    .then(
+      comma_separated_parser(arm)
+        .delimited_by(padded_parser(just('{')), padded_parser(just('}'))),
+    )
+    .map(|(subject, arms)| Expression::Match(Box::new(subject), arms))
+    .map_with(|ast, error| (ast, error.span()))
+}
+
+fn pattern_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, Spanned<Pattern>, ParserError<'a>> + Clone {
+  recursive(|pattern| {
+    let literal = choice((
+      padded_parser(just('-')).or_not().then(number_parser()).map(
+        |(sign, number)| match sign {
+          Some(_) => Expression::Number(number.neg()),
+          None => Expression::Number(number),
+        },
+      ),
+      string_parser().map(Expression::String),
+      keyword_parser("true").to(Expression::Boolean(true)),
+      keyword_parser("false").to(Expression::Boolean(false)),
+      keyword_parser("null").to(Expression::Null),
+    ))
+    .map(Pattern::Literal);
+
+    let binding = identifier_parser()
+      .map(|name| if name == "_" { None } else { Some(name) });
+
+    let type_name =
+      padded_parser(text::ident().validate(|name: &str, extra, emitter| {
+        if !TYPE_NAMES.contains(&name) {
+          emitter
+            .emit(Rich::custom(extra.span(), format!("Unknown type `{name}`")));
+        }
+
+        name.to_owned()
+      }));
+
+    let typed = binding
+      .clone()
+      .then_ignore(padded_parser(just(':')))
+      .then(type_name)
+      .map(|(name, type_name)| Pattern::Type(name, type_name));
+
+    let simple = binding
+      .map(|name| name.map_or(Pattern::Wildcard, Pattern::Binding))
+      .map_with(|pattern, error| (pattern, error.span()));
+
+    let rest = padded_parser(just("..."))
+      .ignore_then(simple.clone())
+      .map(Box::new)
+      .then_ignore(padded_parser(just(',')).or_not());
+
+    let list = pattern
+      .separated_by(padded_parser(just(',')))
+      .allow_trailing()
+      .collect::<Vec<_>>()
+      .then(rest.or_not())
+      .delimited_by(padded_parser(just('[')), padded_parser(just(']')))
+      .map(|(elements, rest)| Pattern::List(elements, rest));
+
+    choice((literal, typed, list))
+      .map_with(|pattern, error| (pattern, error.span()))
+      .or(simple)
+      .padded_by(padding_parser())
+  })
+}
+
+fn string_parser<'a>()
+-> impl Parser<'a, ParserInput<'a>, String, ParserError<'a>> + Clone {
+  let quoted = |quote: char| {
+    just(quote)
+      .ignore_then(
+        escape_parser()
+          .or(none_of([quote, '\\']))
+          .repeated()
+          .collect::<String>(),
+      )
+      .then_ignore(just(quote))
+  };
+
+  let raw = |quote: char| {
+    just('r')
+      .ignore_then(just(quote))
+      .ignore_then(none_of(quote).repeated().to_slice())
+      .then_ignore(just(quote))
+      .map(str::to_owned)
+  };
+
+  choice((quoted('"'), quoted('\''), raw('"'), raw('\'')))
+}
+
 fn statement_list_parser<'a, P>(
   statement: P,
-) -> impl Parser<'a, &'a str, Vec<Spanned<Statement>>, ParserError<'a>> + Clone
+) -> impl Parser<'a, ParserInput<'a>, Vec<Spanned<Statement>>, ParserError<'a>> + Clone
 where
-  P: Parser<'a, &'a str, Spanned<Statement>, ParserError<'a>> + Clone,
+  P: Parser<'a, ParserInput<'a>, Spanned<Statement>, ParserError<'a>> + Clone,
 {
   statement
     .then(padded_parser(just(';')).or_not())
@@ -131,7 +800,8 @@ where
 }
 
 fn statement_parser<'a>()
--> impl Parser<'a, &'a str, Spanned<Statement>, ParserError<'a>> + Clone {
+-> impl Parser<'a, ParserInput<'a>, Spanned<Statement>, ParserError<'a>> + Clone
+{
   recursive(|statement| {
     let statement_block = statement_list_parser(statement.clone())
       .delimited_by(padded_parser(just('{')), padded_parser(just('}')));
@@ -143,30 +813,92 @@ fn statement_parser<'a>()
       (AssignmentTarget::Identifier(name), span)
     });
 
-    let assignment_target = simple_ident.foldl(
-      index_parser(expression.clone()).repeated(),
-      |base, (index, span)| {
-        let span = (base.1.start..span.end).into();
+    let assignment_target = simple_ident
+      .clone()
+      .foldl(
+        index_parser(expression.clone()).repeated(),
+        |base, (index, span)| {
+          let span = base.1.context.span(base.1.start..span.end);
 
-        let target =
-          AssignmentTarget::ListAccess(Box::new(base), Box::new(index));
+          let target =
+            AssignmentTarget::ListAccess(Box::new(base), Box::new(index));
 
-        (target, span)
-      },
-    );
+          (target, span)
+        },
+      )
+      .then(slice_parser(expression.clone()).or_not())
+      .map(|(base, slice)| match slice {
+        Some((slice, span)) => {
+          let span = base.1.context.span(base.1.start..span.end);
+          (AssignmentTarget::Slice(Box::new(base), slice), span)
+        }
+        None => base,
+      });
+
+    let pattern = recursive(|pattern| {
+      assignment_target.clone().or(
+        comma_separated_parser(pattern)
+          .delimited_by(padded_parser(just('[')), padded_parser(just(']')))
+          .map(AssignmentTarget::List)
+          .map_with(|target, error| (target, error.span())),
+      )
+    });
+
+    let declaration_pattern = recursive(|pattern| {
+      simple_ident.clone().or(
+        comma_separated_parser(pattern)
+          .delimited_by(padded_parser(just('[')), padded_parser(just(']')))
+          .map(AssignmentTarget::List)
+          .map_with(|target, error| (target, error.span())),
+      )
+    });
+
+    let let_statement = keyword_parser("let")
+      .ignore_then(declaration_pattern.clone())
+      .then(
+        padded_parser(just('='))
+          .ignore_then(expression.clone())
+          .or_not(),
+      )
+      .map(|(target, value)| Statement::Let(target, value))
+      .map_with(|ast, error| (ast, error.span()));
+
+    let const_statement = keyword_parser("const")
+      .ignore_then(declaration_pattern)
+      .then_ignore(padded_parser(just('=')))
+      .then(expression.clone())
+      .map(|(target, value)| Statement::Const(target, value))
+      .map_with(|ast, error| (ast, error.span()));
 
-    let assignment_statement = assignment_target
+    let assignment_statement = pattern
+      .clone()
       .then_ignore(padded_parser(just('=')))
       .then(expression.clone())
       .map(|(lhs, rhs)| Statement::Assignment(lhs, rhs))
       .map_with(|ast, error| (ast, error.span()));
 
+    let compound_assignment_statement = assignment_target
+      .clone()
+      .then(padded_parser(choice((
+        just("+=").to(BinaryOp::Add),
+        just("-=").to(BinaryOp::Subtract),
+        just("*=").to(BinaryOp::Multiply),
+        just("/=").to(BinaryOp::Divide),
+        just("%=").to(BinaryOp::Modulo),
+        just("^=").to(BinaryOp::Power),
+        just("&=").to(BinaryOp::BitwiseAnd),
+        just("|=").to(BinaryOp::BitwiseOr),
+        just("~=").to(BinaryOp::BitwiseXor),
+        just("<<=").to(BinaryOp::ShiftLeft),
+        just(">>=").to(BinaryOp::ShiftRight),
+      ))))
+      .then(expression.clone())
+      .map(|((lhs, op), rhs)| Statement::CompoundAssignment(lhs, op, rhs))
+      .map_with(|ast, error| (ast, error.span()));
+
     let function_statement = keyword_parser("fn")
       .ignore_then(identifier_parser())
-      .then(
-        comma_separated_parser(identifier_parser())
-          .delimited_by(padded_parser(just('(')), padded_parser(just(')'))),
-      )
+      .then(parameters_parser(expression.clone()))
       .then(statement_block.clone())
       .map(|((name, params), body)| Statement::Function(name, params, body))
       .map_with(|ast, error| (ast, error.span()));
@@ -180,16 +912,26 @@ fn statement_parser<'a>()
       .clone()
       .delimited_by(padded_parser(just('(')), padded_parser(just(')')));
 
-    let if_statement = keyword_parser("if")
+    let if_branch = keyword_parser("if")
       .ignore_then(condition_parser.clone())
-      .then(statement_block.clone())
+      .then(statement_block.clone());
+
+    let if_statement = if_branch
+      .clone()
+      .then(
+        keyword_parser("else")
+          .ignore_then(if_branch)
+          .repeated()
+          .collect::<Vec<_>>(),
+      )
       .then(
         keyword_parser("else")
           .ignore_then(statement_block.clone())
           .or_not(),
       )
-      .map(|((condition, then_branch), else_branch)| {
-        Statement::If(condition, then_branch, else_branch)
+      .map(|((branch, mut branches), else_branch)| {
+        branches.insert(0, branch);
+        Statement::If(branches, else_branch)
       })
       .map_with(|ast, error| (ast, error.span()));
 
@@ -200,11 +942,11 @@ fn statement_parser<'a>()
       .map_with(|ast, error| (ast, error.span()));
 
     let for_statement = keyword_parser("for")
-      .ignore_then(identifier_parser())
+      .ignore_then(pattern)
       .then_ignore(keyword_parser("in"))
       .then(expression.clone())
       .then(statement_block.clone())
-      .map(|((name, iterable), body)| Statement::For(name, iterable, body))
+      .map(|((target, iterable), body)| Statement::For(target, iterable, body))
       .map_with(|ast, error| (ast, error.span()));
 
     let loop_statement = keyword_parser("loop")
@@ -212,11 +954,59 @@ fn statement_parser<'a>()
       .map(Statement::Loop)
       .map_with(|ast, error| (ast, error.span()));
 
+    let import_statement = keyword_parser("import")
+      .ignore_then(padded_parser(
+        string_parser().map_with(|path, error| (path, error.span())),
+      ))
+      .then(
+        keyword_parser("as")
+          .ignore_then(
+            identifier_parser().map_with(|name, error| (name, error.span())),
+          )
+          .or_not(),
+      )
+      .map(|(path, alias)| Statement::Import(path, alias))
+      .map_with(|ast, error| (ast, error.span()));
+
     let return_statement = keyword_parser("return")
       .ignore_then(expression.clone().or_not())
       .map(Statement::Return)
       .map_with(|ast, error| (ast, error.span()));
 
+    let throw_statement = keyword_parser("throw")
+      .ignore_then(expression.clone())
+      .map(Statement::Throw)
+      .map_with(|ast, error| (ast, error.span()));
+
+    let try_statement = keyword_parser("try")
+      .ignore_then(statement_block.clone())
+      .then(
+        keyword_parser("catch")
+          .ignore_then(
+            identifier_parser()
+              .map_with(|name, error| (name, error.span()))
+              .delimited_by(padded_parser(just('(')), padded_parser(just(')'))),
+          )
+          .then(statement_block.clone())
+          .or_not(),
+      )
+      .then(
+        keyword_parser("finally")
+          .ignore_then(statement_block.clone())
+          .or_not(),
+      )
+      .validate(|((body, catch), finally), error, emitter| {
+        if catch.is_none() && finally.is_none() {
+          emitter.emit(Rich::custom(
+            error.span(),
+            "`try` requires a `catch` or `finally` block",
+          ));
+        }
+
+        Statement::Try(body, catch, finally)
+      })
+      .map_with(|ast, error| (ast, error.span()));
+
     let break_statement = keyword_parser("break")
       .map(|()| Statement::Break)
       .map_with(|ast, error| (ast, error.span()));
@@ -230,14 +1020,20 @@ fn statement_parser<'a>()
       .map_with(|ast, error| (ast, error.span()));
 
     choice((
+      let_statement,
+      const_statement,
       assignment_statement,
+      compound_assignment_statement,
       function_statement,
       block_statement,
       if_statement,
       while_statement,
       for_statement,
       loop_statement,
+      import_statement,
       return_statement,
+      throw_statement,
+      try_statement,
       break_statement,
       continue_statement,
       expression_statement,
@@ -249,20 +1045,16 @@ fn statement_parser<'a>()
 
 fn expression_parser<'a, P>(
   statement_block: P,
-) -> impl Parser<'a, &'a str, Spanned<Expression>, ParserError<'a>> + Clone
+) -> impl Parser<'a, ParserInput<'a>, Spanned<Expression>, ParserError<'a>> + Clone
 where
-  P: Parser<'a, &'a str, Vec<Spanned<Statement>>, ParserError<'a>> + Clone,
+  P: Parser<'a, ParserInput<'a>, Vec<Spanned<Statement>>, ParserError<'a>>
+    + Clone,
   P: 'a,
 {
   let identifier = identifier_parser();
 
   recursive(|expression| {
-    let number = text::int(10)
-      .then(just('.').then(text::digits(10)).or_not())
-      .to_slice()
-      .map(|number| Number::try_from(number).unwrap())
-      .map(Expression::Number)
-      .map_with(|ast, error| (ast, error.span()));
+    let number = coefficient_parser(expression.clone());
 
     let boolean = choice((
       keyword_parser("true").to(true),
@@ -275,32 +1067,32 @@ where
       .map(|()| Expression::Null)
       .map_with(|ast, error| (ast, error.span()));
 
-    let double_quoted_string = just('"')
-      .ignore_then(none_of('"').repeated().to_slice())
-      .then_ignore(just('"'))
-      .map(str::to_owned)
-      .map(Expression::String)
-      .map_with(|ast, error| (ast, error.span()));
-
-    let single_quoted_string = just('\'')
-      .ignore_then(none_of('\'').repeated().to_slice())
-      .then_ignore(just('\''))
-      .map(str::to_owned)
+    let string = string_parser()
       .map(Expression::String)
       .map_with(|ast, error| (ast, error.span()));
 
-    let string = double_quoted_string.or(single_quoted_string);
-
-    let arguments = comma_separated_parser(expression.clone())
-      .delimited_by(padded_parser(just('(')), padded_parser(just(')')))
-      .padded_by(padding_parser())
-      .map_with(|arguments, error| (arguments, error.span()));
+    let arguments = arguments_parser(expression.clone());
 
-    let function = keyword_parser("fn")
+    let conditional = keyword_parser("if")
       .ignore_then(
-        comma_separated_parser(identifier_parser())
+        expression
+          .clone()
           .delimited_by(padded_parser(just('(')), padded_parser(just(')'))),
       )
+      .then(expression.clone())
+      .then_ignore(keyword_parser("else"))
+      .then(expression.clone())
+      .map(|((condition, then_branch), else_branch)| {
+        Expression::Conditional(
+          Box::new(condition),
+          Box::new(then_branch),
+          Box::new(else_branch),
+        )
+      })
+      .map_with(|ast, error| (ast, error.span()));
+
+    let function = keyword_parser("fn")
+      .ignore_then(parameters_parser(expression.clone()))
       .then(statement_block.clone())
       .map(|(params, body)| Expression::Function(params, body))
       .map_with(|ast, error| (ast, error.span()));
@@ -314,21 +1106,37 @@ where
       .map(Expression::List)
       .map_with(|ast, error| (ast, error.span()));
 
+    let map = comma_separated_parser(
+      expression
+        .clone()
+        .then_ignore(padded_parser(just(':')))
+        .then(expression.clone()),
+    )
+    .delimited_by(padded_parser(just('{')), padded_parser(just('}')))
+    .map(Expression::Map)
+    .map_with(|ast, error| (ast, error.span()));
+
     let atom = number
       .or(boolean)
       .or(null)
       .or(expression.clone().delimited_by(just('('), just(')')))
+      .or(absolute_value_parser(expression.clone()))
+      .or(conditional)
+      .or(match_parser(expression.clone()))
       .or(function)
       .or(list)
-      .or(identifier)
+      .or(map)
       .or(string)
+      .or(interpolation_parser(expression.clone(), '"'))
+      .or(interpolation_parser(expression.clone(), '\''))
+      .or(identifier)
       .padded_by(padding_parser());
 
     let binary =
       |lhs: Spanned<Expression>,
        op: BinaryOp,
        rhs: Spanned<Expression>,
-       error: &mut MapExtra<'a, '_, &'a str, ParserError<'a>>| {
+       error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
         (
           Expression::BinaryOp(op, Box::new(lhs), Box::new(rhs)),
           error.span(),
@@ -338,91 +1146,169 @@ where
     let unary =
       |op: UnaryOp,
        rhs: Spanned<Expression>,
-       error: &mut MapExtra<'a, '_, &'a str, ParserError<'a>>| {
+       error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
         (Expression::UnaryOp(op, Box::new(rhs)), error.span())
       };
 
     atom.pratt((
       postfix(
-        8,
+        15,
         arguments,
-        |function,
-         (arguments, _),
-         error: &mut MapExtra<'a, '_, &'a str, ParserError<'a>>| {
-          let span = error.span();
-
-          let expression =
-            Expression::FunctionCall(Box::new(function), arguments);
-
-          (expression, span)
+        fold_function_call,
+      ),
+      postfix(
+        15,
+        slice_parser(expression.clone()),
+        |list,
+         (slice, _),
+         error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
+          implicit_operand(list, error, |list| {
+            Expression::Slice(Box::new(list), slice)
+          })
         },
       ),
       postfix(
-        8,
+        15,
         index_parser(expression.clone()),
         |list,
          (index, _),
-         error: &mut MapExtra<'a, '_, &'a str, ParserError<'a>>| {
-          let span = error.span();
-
-          let expression =
-            Expression::ListAccess(Box::new(list), Box::new(index));
-
-          (expression, span)
+         error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
+          implicit_operand(list, error, |list| {
+            Expression::ListAccess(Box::new(list), Box::new(index))
+          })
         },
       ),
-      prefix(7, padded_parser(just('-')).to(UnaryOp::Negate), unary),
-      prefix(7, padded_parser(just('!')).to(UnaryOp::Not), unary),
+      postfix(
+        15,
+        padded_parser(just('.').then_ignore(just('.').not())).ignore_then(
+          identifier_parser().map_with(|name, error| (name, error.span())),
+        ),
+        |base, name, error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
+          implicit_operand(base, error, |base| {
+            Expression::Member(Box::new(base), name)
+          })
+        },
+      ),
+      postfix(15, factorial_parser(), fold_factorial),
+      postfix(15, superscript_parser(), fold_superscript),
+      prefix(14, padded_parser(one_of("-−")).to(UnaryOp::Negate), unary),
+      prefix(14, padded_parser(just('!')).to(UnaryOp::Not), unary),
+      prefix(14, padded_parser(just('~')).to(UnaryOp::BitwiseNot), unary),
+      prefix(14, padded_parser(just('√')).to(UnaryOp::SquareRoot), unary),
       infix(
-        right(6),
+        right(13),
         padded_parser(just('^')).to(BinaryOp::Power),
-        binary,
+        |lhs,
+         op,
+         rhs: Spanned<Expression>,
+         error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
+          implicit_operand(lhs, error, |lhs| {
+            Expression::BinaryOp(op, Box::new(lhs), Box::new(rhs))
+          })
+        },
       ),
       infix(
-        left(5),
+        left(12),
         choice((
+          padded_parser(just(".*")).to(BinaryOp::Hadamard),
           padded_parser(just('%')).to(BinaryOp::Modulo),
-          padded_parser(just('*')).to(BinaryOp::Multiply),
-          padded_parser(just('/')).to(BinaryOp::Divide),
+          padded_parser(one_of("*×")).to(BinaryOp::Multiply),
+          padded_parser(one_of("/÷")).to(BinaryOp::Divide),
         )),
         binary,
       ),
       infix(
-        left(4),
+        left(11),
         choice((
           padded_parser(just('+')).to(BinaryOp::Add),
-          padded_parser(just('-')).to(BinaryOp::Subtract),
+          padded_parser(one_of("-−")).to(BinaryOp::Subtract),
         )),
         binary,
       ),
       infix(
-        left(3),
+        left(10),
         choice((
-          padded_parser(just(">=")).to(BinaryOp::GreaterThanEqual),
-          padded_parser(just("<=")).to(BinaryOp::LessThanEqual),
-          padded_parser(just(">")).to(BinaryOp::GreaterThan),
-          padded_parser(just("<")).to(BinaryOp::LessThan),
+          padded_parser(just("<<")).to(BinaryOp::ShiftLeft),
+          padded_parser(just(">>")).to(BinaryOp::ShiftRight),
         )),
         binary,
       ),
       infix(
-        left(2),
+        left(9),
+        padded_parser(just('&').then(just('&').not())).to(BinaryOp::BitwiseAnd),
+        binary,
+      ),
+      infix(
+        left(8),
+        padded_parser(just('~')).to(BinaryOp::BitwiseXor),
+        binary,
+      ),
+      infix(
+        left(7),
+        padded_parser(just('|').then(just('|').not()))
+          .then(
+            one_of("-−!~√")
+              .not()
+              .labelled("parenthesized operand after `|`"),
+          )
+          .to(BinaryOp::BitwiseOr),
+        binary,
+      ),
+      infix(
+        left(6),
+        choice((
+          padded_parser(just("..=")).to(BinaryOp::RangeInclusive),
+          padded_parser(just("..")).to(BinaryOp::Range),
+        )),
+        binary,
+      ),
+      infix(
+        left(5),
+        comparison_operator_parser(),
+        chain_comparison,
+      ),
+      infix(
+        left(4),
         choice((
           padded_parser(just("==")).to(BinaryOp::Equal),
-          padded_parser(just("!=")).to(BinaryOp::NotEqual),
+          padded_parser(just("!=").or(just("≠"))).to(BinaryOp::NotEqual),
         )),
         binary,
       ),
       infix(
-        left(1),
+        left(3),
         padded_parser(just("&&")).to(BinaryOp::LogicalAnd),
         binary,
       ),
       infix(
-        left(0),
+        left(2),
         padded_parser(just("||")).to(BinaryOp::LogicalOr),
         binary,
       ),
+      infix(
+        left(1),
+        padded_parser(just("??")).to(BinaryOp::Coalesce),
+        binary,
+      ),
+      infix(
+        right(0),
+        padded_parser(just('?').then(just('?').not()))
+          .ignore_then(expression.clone())
+          .then_ignore(padded_parser(just(':'))),
+        |condition: Spanned<Expression>,
+         then_branch: Spanned<Expression>,
+         else_branch: Spanned<Expression>,
+         error: &mut MapExtra<'a, '_, ParserInput<'a>, ParserError<'a>>| {
+          (
+            Expression::Conditional(
+              Box::new(condition),
+              Box::new(then_branch),
+              Box::new(else_branch),
+            ),
+            error.span(),
+          )
+        },
+      ),
     ))
   })
 }
@@ -466,50 +1352,213 @@ mod tests {
         Err(errors) => {
           assert_eq!(errors.len(), self.errors.len(), "Error count mismatch");
 
-          for (error, expected) in errors.iter().zip(self.errors.iter()) {
-            assert_eq!(error, expected, "Error mismatch");
-          }
-        }
-      }
-    }
+          for (error, expected) in errors.iter().zip(self.errors.iter()) {
+            assert_eq!(error, expected, "Error mismatch");
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn absolute_value() {
+    Test::new()
+      .program("|x - 1| + 2")
+      .ast("statements(expression(binary_op(+, unary_op(abs, binary_op(-, identifier(x), number(1))), number(2))))")
+      .run();
+
+    Test::new()
+      .program("|a| - |-b|")
+      .ast("statements(expression(binary_op(-, unary_op(abs, identifier(a)), unary_op(abs, unary_op(-, identifier(b))))))")
+      .run();
+
+    Test::new()
+      .program("|a | b|")
+      .errors(vec![Error::new(
+        SourceId::default().span(1..6),
+        "Ambiguous `|` inside absolute value bars, wrap it in parentheses",
+      )])
+      .run();
+  }
+
+  #[test]
+  fn assignment() {
+    Test::new()
+      .program("x = 5")
+      .ast("statements(assignment(identifier(x), number(5)))")
+      .run();
+
+    Test::new()
+      .program("foo[0][1] = bar")
+      .ast("statements(assignment(list_access(list_access(identifier(foo), number(0)), number(1)), identifier(bar)))")
+      .run();
+  }
+
+  #[test]
+  fn bitwise_operators() {
+    Test::new()
+      .program("a | b ~ c & d << 1")
+      .ast("statements(expression(binary_op(|, identifier(a), binary_op(~, identifier(b), binary_op(&, identifier(c), binary_op(<<, identifier(d), number(1)))))))")
+      .run();
+
+    Test::new()
+      .program("x >> 1 + 2 < y && ~z | w == 0")
+      .ast("statements(expression(binary_op(&&, binary_op(<, binary_op(>>, identifier(x), binary_op(+, number(1), number(2))), identifier(y)), binary_op(==, binary_op(|, unary_op(~, identifier(z)), identifier(w)), number(0)))))")
+      .run();
+
+    Test::new()
+      .program("a || b & c")
+      .ast("statements(expression(binary_op(||, identifier(a), binary_op(&, identifier(b), identifier(c)))))")
+      .run();
+  }
+
+  #[test]
+  fn break_statement() {
+    Test::new().program("break").ast("statements(break)").run();
+  }
+
+  #[test]
+  fn chained_comparisons() {
+    Test::new()
+      .program("0 < x <= 1")
+      .ast("statements(expression(comparison(number(0), <, identifier(x), <=, number(1))))")
+      .run();
+
+    Test::new()
+      .program("a > b >= c > d == e")
+      .ast("statements(expression(binary_op(==, comparison(identifier(a), >, identifier(b), >=, identifier(c), >, identifier(d)), identifier(e))))")
+      .run();
+
+    Test::new()
+      .program("(a < b) < c")
+      .ast("statements(expression(binary_op(<, binary_op(<, identifier(a), identifier(b)), identifier(c))))")
+      .run();
+
+    Test::new()
+      .program("a < b > c")
+      .errors(vec![Error::new(
+        SourceId::default().span(0..9),
+        "Ambiguous comparison chain mixing `<` and `>`, split it with `&&`",
+      )])
+      .run();
+  }
+
+  #[test]
+  fn comments() {
+    Test::new()
+      .program("// foo\n// bar\n")
+      .ast("statements()")
+      .run();
+
+    Test::new()
+      .program("// foo\na = [1, // bar\n 2,]\na[// baz\n0] + 3 // bob")
+      .ast("statements(assignment(identifier(a), list(number(1), number(2))), expression(binary_op(+, list_access(identifier(a), number(0)), number(3))))")
+      .run();
+  }
+
+  #[test]
+  fn compound_assignment() {
+    Test::new()
+      .program("x += 1")
+      .ast("statements(compound_assignment(+, identifier(x), number(1)))")
+      .run();
+
+    Test::new()
+      .program("grid[i][j] ^= 2 * 3")
+      .ast("statements(compound_assignment(^, list_access(list_access(identifier(grid), identifier(i)), identifier(j)), binary_op(*, number(2), number(3))))")
+      .run();
+
+    Test::new()
+      .program("a -= 1; b *= 2; c /= 3; d %= 4")
+      .ast("statements(compound_assignment(-, identifier(a), number(1)), compound_assignment(*, identifier(b), number(2)), compound_assignment(/, identifier(c), number(3)), compound_assignment(%, identifier(d), number(4)))")
+      .run();
+
+    Test::new()
+      .program("a &= 1; b |= 2; c ~= 3; d <<= 4; e >>= 5")
+      .ast("statements(compound_assignment(&, identifier(a), number(1)), compound_assignment(|, identifier(b), number(2)), compound_assignment(~, identifier(c), number(3)), compound_assignment(<<, identifier(d), number(4)), compound_assignment(>>, identifier(e), number(5)))")
+      .run();
   }
 
   #[test]
-  fn assignment() {
+  fn conditional_expression() {
     Test::new()
-      .program("x = 5")
-      .ast("statements(assignment(identifier(x), number(5)))")
+      .program("x = if (a > 1) b else c + 1")
+      .ast("statements(assignment(identifier(x), conditional(binary_op(>, identifier(a), number(1)), identifier(b), binary_op(+, identifier(c), number(1)))))")
       .run();
 
     Test::new()
-      .program("foo[0][1] = bar")
-      .ast("statements(assignment(list_access(list_access(identifier(foo), number(0)), number(1)), identifier(bar)))")
+      .program("a ? b : c ? d : e")
+      .ast("statements(expression(conditional(identifier(a), identifier(b), conditional(identifier(c), identifier(d), identifier(e)))))")
+      .run();
+
+    Test::new()
+      .program("a || b ? 1 + 2 : 3")
+      .ast("statements(expression(conditional(binary_op(||, identifier(a), identifier(b)), binary_op(+, number(1), number(2)), number(3))))")
       .run();
   }
 
   #[test]
-  fn break_statement() {
-    Test::new().program("break").ast("statements(break)").run();
+  fn continue_statement() {
+    Test::new()
+      .program("continue")
+      .ast("statements(continue)")
+      .run();
   }
 
   #[test]
-  fn comments() {
+  fn destructuring() {
     Test::new()
-      .program("// foo\n// bar\n")
-      .ast("statements()")
+      .program("[a, [b, xs[0]]] = f()")
+      .ast("statements(assignment(list(identifier(a), list(identifier(b), list_access(identifier(xs), number(0)))), function_call(identifier(f))))")
       .run();
 
     Test::new()
-      .program("// foo\na = [1, // bar\n 2,]\na[// baz\n0] + 3 // bob")
-      .ast("statements(assignment(identifier(a), list(number(1), number(2))), expression(binary_op(+, list_access(identifier(a), number(0)), number(3))))")
+      .program("for [i, x] in pairs { x }")
+      .ast("statements(for(list(identifier(i), identifier(x)), identifier(pairs), block(expression(identifier(x)))))")
+      .run();
+
+    Test::new()
+      .program("[a, b] == [1, 2]")
+      .ast("statements(expression(binary_op(==, list(identifier(a), identifier(b)), list(number(1), number(2)))))")
       .run();
   }
 
   #[test]
-  fn continue_statement() {
+  fn else_if_chain() {
     Test::new()
-      .program("continue")
-      .ast("statements(continue)")
+      .program("if (x > 5) { y = 1 } else if (x > 2) { y = 2 } else if (x > 0) { y = 3 } else { y = 4 }")
+      .ast("statements(if(binary_op(>, identifier(x), number(5)), block(assignment(identifier(y), number(1))), binary_op(>, identifier(x), number(2)), block(assignment(identifier(y), number(2))), binary_op(>, identifier(x), number(0)), block(assignment(identifier(y), number(3))), block(assignment(identifier(y), number(4)))))")
+      .run();
+
+    Test::new()
+      .program("if (a) { 1 } else if (b) { 2 }")
+      .ast("statements(if(identifier(a), block(expression(number(1))), identifier(b), block(expression(number(2)))))")
+      .run();
+  }
+
+  #[test]
+  fn factorial() {
+    Test::new()
+      .program("5! + n! != 0")
+      .ast("statements(expression(binary_op(!=, binary_op(+, unary_op(factorial, number(5)), unary_op(factorial, identifier(n))), number(0))))")
+      .run();
+
+    Test::new()
+      .program("f(x)!^2")
+      .ast("statements(expression(binary_op(^, unary_op(factorial, function_call(identifier(f), identifier(x))), number(2))))")
+      .run();
+
+    Test::new()
+      .program("n !")
+      .errors(vec![Error::new(
+        SourceId::default().span(0..3),
+        "Factorial `!` must directly follow its operand",
+      )])
+      .run();
+
+    Test::new()
+      .program("println(1)\n!true")
+      .ast("statements(expression(function_call(identifier(println), number(1))), expression(unary_op(!, boolean(true))))")
       .run();
   }
 
@@ -517,7 +1566,7 @@ mod tests {
   fn for_loop() {
     Test::new()
       .program("for x in [1, 2, 3] { println(x) }")
-      .ast("statements(for(x, list(number(1), number(2), number(3)), block(expression(function_call(identifier(println), identifier(x))))))")
+      .ast("statements(for(identifier(x), list(number(1), number(2), number(3)), block(expression(function_call(identifier(println), identifier(x))))))")
       .run();
   }
 
@@ -542,6 +1591,35 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn function_parameters() {
+    Test::new()
+      .program("fn f(x, base = 10, ...rest) { return x }")
+      .ast("statements(function(f, [x, base = number(10), ...rest], block(return(identifier(x)))))")
+      .run();
+
+    Test::new()
+      .program("fn(a = 1 + 2) { a }")
+      .ast("statements(expression(function([a = binary_op(+, number(1), number(2))], block(expression(identifier(a))))))")
+      .run();
+
+    Test::new()
+      .program("fn f(a = 1, b) {}")
+      .errors(vec![Error::new(
+        SourceId::default().span(12..13),
+        "Required parameter `b` cannot follow a parameter with a default value",
+      )])
+      .run();
+
+    Test::new()
+      .program("fn f(...a, b) {}")
+      .errors(vec![Error::new(
+        SourceId::default().span(5..9),
+        "Rest parameter `...a` must be the last parameter",
+      )])
+      .run();
+  }
+
   #[test]
   fn function_with_return() {
     Test::new()
@@ -566,6 +1644,45 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn implicit_multiplication() {
+    Test::new()
+      .program("2x + 3(x + 1)")
+      .ast("statements(expression(binary_op(+, binary_op(*, number(2), identifier(x)), binary_op(*, number(3), binary_op(+, identifier(x), number(1))))))")
+      .run();
+
+    Test::new()
+      .program("2x^2 - 1/2x")
+      .ast("statements(expression(binary_op(-, binary_op(*, number(2), binary_op(^, identifier(x), number(2))), binary_op(/, number(1), binary_op(*, number(2), identifier(x))))))")
+      .run();
+
+    Test::new()
+      .program("(2x)^2 + 2sin(x)!")
+      .ast("statements(expression(binary_op(+, binary_op(^, binary_op(*, number(2), identifier(x)), number(2)), binary_op(*, number(2), unary_op(factorial, function_call(identifier(sin), identifier(x)))))))")
+      .run();
+
+    Test::new()
+      .program("2 (x)")
+      .errors(vec![Error::new(
+        SourceId::default().span(0..5),
+        "Cannot call a number, use `*` to multiply",
+      )])
+      .run();
+  }
+
+  #[test]
+  fn import_statement() {
+    Test::new()
+      .program("import \"lib/math.val\"")
+      .ast("statements(import(\"lib/math.val\"))")
+      .run();
+
+    Test::new()
+      .program("import 'math.val' as m; m.square(2)")
+      .ast("statements(import(\"math.val\", m), expression(function_call(member(identifier(m), square), number(2))))")
+      .run();
+  }
+
   #[test]
   fn integer_literal() {
     Test::new()
@@ -574,17 +1691,54 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn interpolation() {
+    Test::new()
+      .program("f\"fib({i}) = {fib(i)}\"")
+      .ast("statements(expression(interpolation(string(\"fib(\"), identifier(i), string(\") = \"), function_call(identifier(fib), identifier(i)))))")
+      .run();
+
+    Test::new()
+      .program("f'{{{ x }}}\\n{'a' + \"b\"}'")
+      .ast("statements(expression(interpolation(string(\"{\"), identifier(x), string(\"}\\n\"), binary_op(+, string(\"a\"), string(\"b\")))))")
+      .run();
+
+    Test::new()
+      .program("f\"\"")
+      .ast("statements(expression(interpolation()))")
+      .run();
+
+    Test::new()
+      .program("f\"a}b\"")
+      .errors(vec![Error::new(
+        SourceId::default().span(4..5),
+        "found 'b' expected '}'",
+      )])
+      .run();
+  }
+
   #[test]
   fn invalid_operator() {
     Test::new()
       .program("2 +* 3")
       .errors(vec![Error::new(
-        SimpleSpan::from(3..4),
-        "found '*' expected '-', '!', int, '\"true\"', '\"false\"', '\"null\"', '(', '\"fn\"', '[', identifier, '\"', or '''",
+        SourceId::default().span(3..4),
+        "found '*' expected '-', '−', '!', '~', '√', '0', int, '\"true\"', '\"false\"', '\"null\"', '(', '|', '\"if\"', '\"match\"', '\"fn\"', '[', '{', '\"', ''', 'r', 'f', or identifier",
       )])
       .run();
   }
 
+  #[test]
+  fn let_and_const() {
+    Test::new()
+      .program("let x = 1; let [a, [b]] = xs; let y; const z = 2")
+      .ast("statements(let(identifier(x), number(1)), let(list(identifier(a), list(identifier(b))), identifier(xs)), let(identifier(y)), const(identifier(z), number(2)))")
+      .run();
+
+    assert!(parse("let xs[0] = 1").is_err());
+    assert!(parse("const x").is_err());
+  }
+
   #[test]
   fn list_access() {
     Test::new()
@@ -633,13 +1787,71 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn map_literal() {
+    Test::new()
+      .program("m = {'a': 1, 2: [3], }")
+      .ast("statements(assignment(identifier(m), map(string(\"a\"): number(1), number(2): list(number(3)))))")
+      .run();
+
+    Test::new()
+      .program("m = {}")
+      .ast("statements(assignment(identifier(m), map()))")
+      .run();
+
+    Test::new()
+      .program("{1 + 1: x}[2]")
+      .ast("statements(expression(list_access(map(binary_op(+, number(1), number(1)): identifier(x)), number(2))))")
+      .run();
+
+    Test::new().program("{}").ast("statements(block())").run();
+  }
+
+  #[test]
+  fn match_expression() {
+    Test::new()
+      .program("match (x) { 0 => a, -1.5 => b, null => c, 'hi' => d }")
+      .ast("statements(expression(match(identifier(x), arm(number(0), identifier(a)), arm(number(-1.5), identifier(b)), arm(null, identifier(c)), arm(string(\"hi\"), identifier(d)))))")
+      .run();
+
+    Test::new()
+      .program("match (xs) { [] => 0, [x, ...rest] if x > 0 => x, [_, ..._] => 1 }")
+      .ast("statements(expression(match(identifier(xs), arm(list_pattern(), number(0)), arm(list_pattern(binding(x), ...binding(rest)), binary_op(>, identifier(x), number(0)), identifier(x)), arm(list_pattern(wildcard, ...wildcard), number(1)))))")
+      .run();
+
+    Test::new()
+      .program("match (v) { n: number => n, _: string => 0, _ => 1, }")
+      .ast("statements(expression(match(identifier(v), arm(type_pattern(n, number), identifier(n)), arm(type_pattern(_, string), number(0)), arm(wildcard, number(1)))))")
+      .run();
+  }
+
+  #[test]
+  fn member_access() {
+    Test::new()
+      .program("a.b.c[0]")
+      .ast("statements(expression(list_access(member(member(identifier(a), b), c), number(0))))")
+      .run();
+
+    Test::new()
+      .program("a..b")
+      .ast(
+        "statements(expression(binary_op(.., identifier(a), identifier(b))))",
+      )
+      .run();
+
+    Test::new()
+      .program("a.*b + 2.*3")
+      .ast("statements(expression(binary_op(+, binary_op(.*, identifier(a), identifier(b)), binary_op(.*, number(2), number(3)))))")
+      .run();
+  }
+
   #[test]
   fn missing_closing_parenthesis() {
     Test::new()
       .program("(2 + 3")
       .errors(vec![Error::new(
-        SimpleSpan::from(6..6),
-        "found end of input expected any, '.', '(', '[', '^', '%', '*', '/', '+', '-', '>', '<', '=', '!', '&', '|', or ')'",
+        SourceId::default().span(6..6),
+        "found end of input expected any, '_', '.', 'e', 'E', identifier, '(', '[', '!', superscript exponent, '^', '%', '*', '×', '/', '÷', '+', '-', '−', '<', '>', '&', '~', '|', '≥', '≤', '\"in\"', '=', '≠', '?', or ')'",
       )])
       .run();
   }
@@ -656,6 +1868,27 @@ mod tests {
     Test::new().program("1 + 2; 3 * 4").ast("statements(expression(binary_op(+, number(1), number(2))), expression(binary_op(*, number(3), number(4))))").run();
   }
 
+  #[test]
+  fn named_arguments() {
+    Test::new()
+      .program("f(1, base: 2, sep: ', ')")
+      .ast("statements(expression(function_call(identifier(f), number(1), base: number(2), sep: string(\", \"))))")
+      .run();
+
+    Test::new()
+      .program("f(a ? b : c)")
+      .ast("statements(expression(function_call(identifier(f), conditional(identifier(a), identifier(b), identifier(c)))))")
+      .run();
+
+    Test::new()
+      .program("f(a: 1, 2)")
+      .errors(vec![Error::new(
+        SourceId::default().span(8..9),
+        "Positional argument cannot follow named arguments",
+      )])
+      .run();
+  }
+
   #[test]
   fn nested_if_statements() {
     Test::new()
@@ -688,6 +1921,47 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn null_coalescing() {
+    Test::new()
+      .program("a ?? b ?? c")
+      .ast("statements(expression(binary_op(??, binary_op(??, identifier(a), identifier(b)), identifier(c))))")
+      .run();
+
+    Test::new()
+      .program("a || b ?? c ? d : e")
+      .ast("statements(expression(conditional(binary_op(??, binary_op(||, identifier(a), identifier(b)), identifier(c)), identifier(d), identifier(e))))")
+      .run();
+  }
+
+  #[test]
+  fn number_literals() {
+    Test::new()
+      .program("0xff + 0b1010 - 0o17")
+      .ast("statements(expression(binary_op(-, binary_op(+, number(255), number(10)), number(15))))")
+      .run();
+
+    Test::new()
+      .program("1_000_000 * 2.5e3 / 1E-2")
+      .ast("statements(expression(binary_op(/, binary_op(*, number(1000000), number(2500)), number(0.01))))")
+      .run();
+
+    for literal in ["1e", "1e+", "1.5e", "0x", "0b", "1_", "1__0", "0x_ff"] {
+      Test::new()
+        .program(literal)
+        .errors(vec![Error::new(
+          SourceId::default().span(0..literal.len()),
+          format!("Invalid number literal `{literal}`"),
+        )])
+        .run();
+    }
+
+    Test::new()
+      .program("2ex")
+      .ast("statements(expression(binary_op(*, number(2), identifier(ex))))")
+      .run();
+  }
+
   #[test]
   fn operator_precedence() {
     Test::new()
@@ -741,7 +2015,7 @@ mod tests {
       assert_eq!(
         parse(program).unwrap_err(),
         [Error::new(
-          SimpleSpan::from(start..start + word.len()),
+          SourceId::default().span(start..start + word.len()),
           format!("`{word}` is a reserved word"),
         )],
       );
@@ -761,6 +2035,32 @@ mod tests {
     }
   }
 
+  #[test]
+  fn ranges_and_membership() {
+    Test::new()
+      .program("0..n - 1")
+      .ast("statements(expression(binary_op(.., number(0), binary_op(-, identifier(n), number(1)))))")
+      .run();
+
+    Test::new()
+      .program("x in 1..=10 && y")
+      .ast("statements(expression(binary_op(&&, binary_op(in, identifier(x), binary_op(..=, number(1), number(10))), identifier(y))))")
+      .run();
+
+    Test::new()
+      .program("for i in 1..3 { i }")
+      .ast("statements(for(identifier(i), binary_op(.., number(1), number(3)), block(expression(identifier(i)))))")
+      .run();
+  }
+
+  #[test]
+  fn raw_strings() {
+    Test::new()
+      .program(r#"r"C:\path\n" + r'\u{41}'"#)
+      .ast(r#"statements(expression(binary_op(+, string("C:\\path\\n"), string("\\u{41}"))))"#)
+      .run();
+  }
+
   #[test]
   fn return_statement() {
     Test::new()
@@ -774,15 +2074,112 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn slicing() {
+    Test::new()
+      .program("xs[1:-1]")
+      .ast("statements(expression(slice(identifier(xs), number(1), unary_op(-, number(1)), _)))")
+      .run();
+
+    Test::new()
+      .program("xs[::2][a ? b : c]")
+      .ast("statements(expression(list_access(slice(identifier(xs), _, _, number(2)), conditional(identifier(a), identifier(b), identifier(c)))))")
+      .run();
+
+    Test::new()
+      .program("xs[0][:] = ys")
+      .ast("statements(assignment(slice(list_access(identifier(xs), number(0)), _, _, _), identifier(ys)))")
+      .run();
+  }
+
+  #[test]
+  fn string_escapes() {
+    Test::new()
+      .program(r#""a\tb\nc\\\"\'\0\r" + 'it\'s' + "\u{1F600}\u{e9}""#)
+      .ast(r#"statements(expression(binary_op(+, binary_op(+, string("a\tb\nc\\\"'\0\r"), string("it's")), string("😀é"))))"#)
+      .run();
+
+    Test::new()
+      .program(r#""foo\qbar""#)
+      .errors(vec![Error::new(
+        SourceId::default().span(4..6),
+        "Invalid escape sequence `\\q`",
+      )])
+      .run();
+
+    Test::new()
+      .program(r#"'\u{110000}' + "\u{}""#)
+      .errors(vec![
+        Error::new(
+          SourceId::default().span(1..11),
+          "Invalid unicode escape `\\u{110000}`",
+        ),
+        Error::new(
+          SourceId::default().span(16..20),
+          "Invalid unicode escape `\\u{}`",
+        ),
+      ])
+      .run();
+  }
+
+  #[test]
+  fn try_statement() {
+    Test::new()
+      .program("try { f() } catch (e) { g(e) } finally { h() }")
+      .ast("statements(try(block(expression(function_call(identifier(f)))), catch(e, block(expression(function_call(identifier(g), identifier(e))))), finally(block(expression(function_call(identifier(h)))))))")
+      .run();
+
+    Test::new()
+      .program("try { throw 'oops' } finally {}")
+      .ast("statements(try(block(throw(string(\"oops\"))), finally(block())))")
+      .run();
+
+    Test::new()
+      .program("try { 1 }")
+      .errors(vec![Error::new(
+        SourceId::default().span(0..9),
+        "`try` requires a `catch` or `finally` block",
+      )])
+      .run();
+  }
+
   #[test]
   fn unclosed_string() {
     Test::new()
       .program("\"unclosed")
       .errors(vec![Error::new(
-        SimpleSpan::from(9..9),
-        "found end of input expected something else, or '\"'",
+        SourceId::default().span(9..9),
+        "found end of input expected '\\', something else, or '\"'",
+      )])
+      .run();
+  }
+
+  #[test]
+  fn unicode_operators() {
+    Test::new()
+      .program("a × b ÷ c − −d")
+      .ast("statements(expression(binary_op(-, binary_op(/, binary_op(*, identifier(a), identifier(b)), identifier(c)), unary_op(-, identifier(d)))))")
+      .run();
+
+    Test::new()
+      .program("a ≤ b ≥ c ≠ d")
+      .errors(vec![Error::new(
+        SourceId::default().span(0..14),
+        "Ambiguous comparison chain mixing `<=` and `>=`, split it with `&&`",
       )])
       .run();
+
+    Test::new()
+      .program("√x² + 2y³ + 10⁻¹²")
+      .ast("statements(expression(binary_op(+, binary_op(+, unary_op(√, binary_op(^, identifier(x), number(2))), binary_op(*, number(2), binary_op(^, identifier(y), number(3)))), binary_op(^, number(10), number(-12)))))")
+      .run();
+
+    Test::new()
+      .program("π ≠ τ")
+      .ast(
+        "statements(expression(binary_op(!=, identifier(π), identifier(τ))))",
+      )
+      .run();
   }
 
   #[test]
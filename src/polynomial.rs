@@ -0,0 +1,611 @@
+use super::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polynomial {
+  coefficients: Vec<Number>,
+}
+
+impl Polynomial {
+  #[must_use]
+  pub fn add(&self, rhs: &Self, config: Config) -> Self {
+    let zero = Number::from(0_i64);
+
+    Self::trimmed(
+      (0..self.coefficients.len().max(rhs.coefficients.len()))
+        .map(|power| {
+          self
+            .coefficients
+            .get(power)
+            .unwrap_or(&zero)
+            .add(rhs.coefficients.get(power).unwrap_or(&zero), config)
+        })
+        .collect(),
+    )
+  }
+
+  #[must_use]
+  pub fn antiderivative(&self, config: Config) -> Self {
+    Self::trimmed(
+      std::iter::once(Number::from(0_i64))
+        .chain(self.coefficients.iter().enumerate().map(
+          |(power, coefficient)| {
+            coefficient
+              .mul(&Number::Exact(Rational::from((1, power + 1))), config)
+          },
+        ))
+        .collect(),
+    )
+  }
+
+  fn approximate_roots(&self, config: Config) -> Vec<Complex> {
+    let precision = config.precision().saturating_mul(3);
+
+    let working = Config {
+      precision,
+      ..config
+    };
+
+    let Some(lead) = self.coefficients.last() else {
+      return Vec::new();
+    };
+
+    let lead = lead.to_complex(working);
+
+    let coefficients = self
+      .coefficients
+      .iter()
+      .rev()
+      .map(|coefficient| coefficient.to_complex(working) / &lead)
+      .collect::<Vec<Complex>>();
+
+    let degree = coefficients.len() - 1;
+
+    let seed = Complex::with_val(precision, (0.4, 0.9));
+
+    let mut roots = Vec::with_capacity(degree);
+
+    let mut root = Complex::with_val(precision, 1);
+
+    for _ in 0..degree {
+      roots.push(root.clone());
+      root *= &seed;
+    }
+
+    let tolerance =
+      Float::with_val(precision, 1) >> precision.saturating_sub(8);
+
+    for _ in 0..1000 {
+      let mut converged = true;
+
+      for index in 0..degree {
+        let value = coefficients
+          .iter()
+          .fold(Complex::with_val(precision, 0), |value, coefficient| {
+            value * &roots[index] + coefficient
+          });
+
+        let denominator = (0..degree).filter(|other| *other != index).fold(
+          Complex::with_val(precision, 1),
+          |denominator, other| {
+            denominator
+              * Complex::with_val(precision, &roots[index] - &roots[other])
+          },
+        );
+
+        let delta = value / denominator;
+
+        let scale = Float::with_val(precision, roots[index].abs_ref())
+          .max(&Float::with_val(precision, 1));
+
+        if Float::with_val(precision, delta.abs_ref())
+          > tolerance.clone() * scale
+        {
+          converged = false;
+        }
+
+        roots[index] -= delta;
+      }
+
+      if converged {
+        break;
+      }
+    }
+
+    roots
+  }
+
+  fn clean(root: Complex, config: Config) -> Number {
+    let precision = root.prec().0;
+
+    let threshold = Float::with_val(precision, 1) >> (config.precision() / 2);
+
+    let scale = Float::with_val(precision, root.abs_ref())
+      .max(&Float::with_val(precision, 1))
+      * &threshold;
+
+    let (re, im) = root.into_real_imag();
+
+    let clean = |part: Float| {
+      if Float::with_val(precision, part.abs_ref()) <= scale {
+        Float::with_val(precision, 0)
+      } else {
+        part
+      }
+    };
+
+    Number::from_complex(Complex::with_val(
+      config.precision(),
+      (clean(re), clean(im)),
+    ))
+  }
+
+  #[must_use]
+  pub fn coefficients(&self) -> Vec<Number> {
+    self.coefficients.iter().rev().cloned().collect()
+  }
+
+  #[must_use]
+  pub fn constant(number: Number) -> Self {
+    Self::trimmed(vec![number])
+  }
+
+  fn convergent(x: &Float, tolerance: &Float) -> Option<Rational> {
+    let target = x.to_rational()?;
+
+    let mut remainder = target.clone();
+
+    let (mut previous_numerator, mut numerator) =
+      (Integer::from(0), Integer::from(1));
+
+    let (mut previous_denominator, mut denominator) =
+      (Integer::from(1), Integer::from(0));
+
+    loop {
+      let term = Integer::from(remainder.floor_ref());
+
+      (previous_numerator, numerator) = (
+        numerator.clone(),
+        term.clone() * &numerator + previous_numerator,
+      );
+
+      (previous_denominator, denominator) = (
+        denominator.clone(),
+        term.clone() * &denominator + previous_denominator,
+      );
+
+      let convergent = Rational::from((numerator.clone(), denominator.clone()));
+
+      let fraction = remainder - term;
+
+      if fraction.is_zero()
+        || Float::with_val(tolerance.prec(), (&convergent - &target).complete())
+          .abs()
+          <= *tolerance
+      {
+        return Some(convergent);
+      }
+
+      remainder = fraction.recip();
+    }
+  }
+
+  #[must_use]
+  pub fn derivative(&self, config: Config) -> Self {
+    Self::trimmed(
+      self
+        .coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(power, coefficient)| {
+          coefficient.mul(&Number::from(power), config)
+        })
+        .collect(),
+    )
+  }
+
+  #[must_use]
+  pub fn display(&self, config: Config) -> String {
+    let mut terms = String::new();
+
+    for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+      if coefficient.is_zero() {
+        continue;
+      }
+
+      let negative = !coefficient.is_complex() && coefficient.is_negative();
+
+      let magnitude = if negative {
+        coefficient.neg()
+      } else {
+        coefficient.clone()
+      };
+
+      let coefficient = if power > 0 && magnitude == Number::from(1_i64) {
+        String::new()
+      } else {
+        let text = magnitude.display(config);
+
+        if power > 0 && (magnitude.is_complex() || text.contains(' ')) {
+          format!("({text})")
+        } else {
+          text
+        }
+      };
+
+      let variable = match power {
+        0 => String::new(),
+        1 => "x".into(),
+        _ => format!("x^{power}"),
+      };
+
+      if !terms.is_empty() {
+        terms.push_str(if negative { " - " } else { " + " });
+      } else if negative {
+        terms.push('-');
+      }
+
+      terms.push_str(&coefficient);
+      terms.push_str(&variable);
+    }
+
+    if terms.is_empty() { "0".into() } else { terms }
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `rhs` is the zero polynomial.
+  pub fn div_rem(
+    &self,
+    rhs: &Self,
+    config: Config,
+  ) -> Result<(Self, Self), Error> {
+    let Some(lead) = rhs.coefficients.last() else {
+      return Err(Error::DivisionByZero);
+    };
+
+    let degree = rhs.coefficients.len() - 1;
+
+    if self.coefficients.len() <= degree {
+      return Ok((Self::default(), self.clone()));
+    }
+
+    let mut remainder = self.coefficients.clone();
+
+    let mut quotient =
+      vec![Number::from(0_i64); self.coefficients.len() - degree];
+
+    for power in (0..quotient.len()).rev() {
+      let coefficient = remainder[power + degree].div(lead, config)?;
+
+      for (offset, divisor) in rhs.coefficients.iter().enumerate() {
+        remainder[power + offset] = remainder[power + offset]
+          .sub(&coefficient.mul(divisor, config), config);
+      }
+
+      quotient[power] = coefficient;
+    }
+
+    remainder.truncate(degree);
+
+    Ok((Self::trimmed(quotient), Self::trimmed(remainder)))
+  }
+
+  #[must_use]
+  pub fn evaluate(&self, x: &Number, config: Config) -> Number {
+    self
+      .coefficients
+      .iter()
+      .rev()
+      .fold(Number::from(0_i64), |value, coefficient| {
+        value.mul(x, config).add(coefficient, config)
+      })
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if either polynomial has approximate coefficients.
+  pub fn gcd(&self, rhs: &Self, config: Config) -> Result<Self, Error> {
+    if !(self.is_exact() && rhs.is_exact()) {
+      return Err(Error::Message(
+        "Cannot take the gcd of polynomials with approximate coefficients"
+          .into(),
+      ));
+    }
+
+    let (mut a, mut b) = (self.clone(), rhs.clone());
+
+    while !b.is_zero() {
+      let remainder = a.div_rem(&b, config)?.1;
+      a = b;
+      b = remainder;
+    }
+
+    match a.coefficients.last() {
+      Some(lead) => Ok(Self::trimmed(
+        a.coefficients
+          .iter()
+          .map(|coefficient| coefficient.div(lead, config))
+          .collect::<Result<Vec<Number>, Error>>()?,
+      )),
+      None => Ok(a),
+    }
+  }
+
+  fn is_exact(&self) -> bool {
+    self.coefficients.iter().all(Number::is_exact)
+  }
+
+  #[must_use]
+  pub fn is_zero(&self) -> bool {
+    self.coefficients.is_empty()
+  }
+
+  #[must_use]
+  pub fn mul(&self, rhs: &Self, config: Config) -> Self {
+    if self.is_zero() || rhs.is_zero() {
+      return Self::default();
+    }
+
+    let mut coefficients = vec![
+      Number::from(0_i64);
+      self.coefficients.len() + rhs.coefficients.len()
+        - 1
+    ];
+
+    for (i, a) in self.coefficients.iter().enumerate() {
+      for (j, b) in rhs.coefficients.iter().enumerate() {
+        coefficients[i + j] =
+          coefficients[i + j].add(&a.mul(b, config), config);
+      }
+    }
+
+    Self::trimmed(coefficients)
+  }
+
+  #[must_use]
+  pub fn neg(&self) -> Self {
+    Self {
+      coefficients: self.coefficients.iter().map(Number::neg).collect(),
+    }
+  }
+
+  #[must_use]
+  pub fn new(coefficients: Vec<Number>) -> Self {
+    Self::trimmed(coefficients.into_iter().rev().collect())
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if `exponent` is negative.
+  pub fn pow(&self, exponent: &Integer, config: Config) -> Result<Self, Error> {
+    if exponent.is_negative() {
+      return Err(Error::Message(
+        "Cannot raise a polynomial to a negative power".into(),
+      ));
+    }
+
+    let mut base = self.clone();
+
+    let mut remaining = exponent.clone();
+
+    let mut result = Self::constant(Number::from(1_i64));
+
+    while remaining > 0 {
+      if remaining.is_odd() {
+        result = result.mul(&base, config);
+      }
+
+      base = base.mul(&base, config);
+      remaining >>= 1;
+    }
+
+    Ok(result)
+  }
+
+  fn rationalize(&self, root: &Complex, config: Config) -> Option<Number> {
+    if !self.is_exact() {
+      return None;
+    }
+
+    let tolerance = Float::with_val(root.prec().0, 1) >> config.precision();
+
+    let candidate = Number::complex(
+      Number::Exact(Self::convergent(root.real(), &tolerance)?),
+      Number::Exact(Self::convergent(root.imag(), &tolerance)?),
+    );
+
+    self
+      .evaluate(&candidate, config)
+      .is_zero()
+      .then_some(candidate)
+  }
+
+  /// # Errors
+  ///
+  /// Returns an error if the polynomial is zero.
+  pub fn roots(&self, config: Config) -> Result<Vec<Number>, Error> {
+    if self.is_zero() {
+      return Err(Error::Message(
+        "Cannot find the roots of the zero polynomial".into(),
+      ));
+    }
+
+    let factors = if self.is_exact() {
+      self.square_free(config)?
+    } else {
+      vec![(self.clone(), 1)]
+    };
+
+    let mut roots = Vec::new();
+
+    for (factor, multiplicity) in factors {
+      for root in factor.approximate_roots(config) {
+        let root = factor
+          .rationalize(&root, config)
+          .unwrap_or_else(|| Self::clean(root, config));
+
+        roots.extend(std::iter::repeat_n(root, multiplicity));
+      }
+    }
+
+    roots.sort_by(|lhs, rhs| {
+      lhs
+        .re()
+        .partial_cmp(&rhs.re())
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| {
+          lhs.im().partial_cmp(&rhs.im()).unwrap_or(Ordering::Equal)
+        })
+    });
+
+    Ok(roots)
+  }
+
+  fn square_free(&self, config: Config) -> Result<Vec<(Self, usize)>, Error> {
+    let derivative = self.derivative(config);
+
+    let divisor = self.gcd(&derivative, config)?;
+
+    let mut remaining = self.div_rem(&divisor, config)?.0;
+
+    let mut difference = derivative
+      .div_rem(&divisor, config)?
+      .0
+      .sub(&remaining.derivative(config), config);
+
+    let mut factors = Vec::new();
+
+    let mut multiplicity = 1;
+
+    while remaining.coefficients.len() > 1 {
+      let factor = remaining.gcd(&difference, config)?;
+
+      remaining = remaining.div_rem(&factor, config)?.0;
+
+      difference = difference
+        .div_rem(&factor, config)?
+        .0
+        .sub(&remaining.derivative(config), config);
+
+      if factor.coefficients.len() > 1 {
+        factors.push((factor, multiplicity));
+      }
+
+      multiplicity += 1;
+    }
+
+    Ok(factors)
+  }
+
+  #[must_use]
+  pub fn sub(&self, rhs: &Self, config: Config) -> Self {
+    self.add(&rhs.neg(), config)
+  }
+
+  fn trimmed(mut coefficients: Vec<Number>) -> Self {
+    while coefficients.last().is_some_and(Number::is_zero) {
+      coefficients.pop();
+    }
+
+    Self { coefficients }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  fn polynomial(coefficients: &[i64]) -> Polynomial {
+    Polynomial::new(coefficients.iter().copied().map(Number::from).collect())
+  }
+
+  #[test]
+  fn display() {
+    let config = Config::default();
+
+    assert_eq!(polynomial(&[1, -3, 2]).display(config), "x^2 - 3x + 2");
+    assert_eq!(polynomial(&[-1, 0, 0, 5]).display(config), "-x^3 + 5");
+    assert_eq!(polynomial(&[2, 1]).display(config), "2x + 1");
+    assert_eq!(polynomial(&[0, 0]).display(config), "0");
+  }
+
+  #[test]
+  fn division_with_remainder() {
+    let config = Config::default();
+
+    let (quotient, remainder) = polynomial(&[1, 0, 0, 1])
+      .div_rem(&polynomial(&[2, 1]), config)
+      .unwrap();
+
+    assert_eq!(quotient.display(config), "0.5x^2 - 0.25x + 0.125");
+    assert_eq!(
+      remainder,
+      Polynomial::constant(Number::Exact(Rational::from((7, 8))))
+    );
+  }
+
+  #[test]
+  fn gcd_is_monic() {
+    let config = Config::default();
+
+    assert_eq!(
+      polynomial(&[2, 0, -2])
+        .gcd(&polynomial(&[3, -6, 3]), config)
+        .unwrap(),
+      polynomial(&[1, -1])
+    );
+
+    assert_eq!(
+      polynomial(&[1, 0, 1])
+        .gcd(&polynomial(&[1, 1]), config)
+        .unwrap(),
+      polynomial(&[1])
+    );
+  }
+
+  #[test]
+  fn roots_keep_multiplicity() {
+    let config = Config::default();
+
+    assert_eq!(
+      polynomial(&[1, -4, 5, -2]).roots(config).unwrap(),
+      vec![
+        Number::from(1_i64),
+        Number::from(1_i64),
+        Number::from(2_i64)
+      ]
+    );
+
+    assert_eq!(
+      polynomial(&[4, 0, 1]).roots(config).unwrap(),
+      vec![
+        Number::complex(
+          Number::from(0_i64),
+          Number::Exact(Rational::from((-1, 2)))
+        ),
+        Number::complex(
+          Number::from(0_i64),
+          Number::Exact(Rational::from((1, 2)))
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn square_free_factors() {
+    let config = Config::default();
+
+    let factors = polynomial(&[1, -1])
+      .pow(&Integer::from(3), config)
+      .unwrap()
+      .mul(&polynomial(&[1, 0, -2]), config)
+      .square_free(config)
+      .unwrap();
+
+    assert_eq!(
+      factors,
+      vec![(polynomial(&[1, 0, -2]), 1), (polynomial(&[1, -1]), 3)]
+    );
+  }
+}
@@ -0,0 +1,140 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Range {
+  end: i128,
+  start: i64,
+  step: i64,
+}
+
+impl Range {
+  pub(crate) fn contains(&self, number: &Number) -> bool {
+    let Some(number) = number.to_i64() else {
+      return false;
+    };
+
+    let offset = i128::from(number) - i128::from(self.start);
+
+    let index = offset / i128::from(self.step);
+
+    offset % i128::from(self.step) == 0
+      && index >= 0
+      && usize::try_from(index).is_ok_and(|index| index < self.len())
+  }
+
+  pub(crate) fn exclusive(start: i64, end: i64, step: i64) -> Self {
+    Self {
+      start,
+      step,
+      end: end.into(),
+    }
+  }
+
+  pub(crate) fn get(&self, index: usize) -> Option<Number> {
+    if index >= self.len() {
+      return None;
+    }
+
+    let value = i128::from(self.start)
+      + i128::try_from(index).ok()? * i128::from(self.step);
+
+    Some(Number::from(i64::try_from(value).ok()?))
+  }
+
+  pub(crate) fn inclusive(start: i64, end: i64) -> Self {
+    Self {
+      start,
+      step: 1,
+      end: i128::from(end) + 1,
+    }
+  }
+
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  pub(crate) fn iter(self) -> impl Iterator<Item = Number> {
+    (0..self.len()).map_while(move |index| self.get(index))
+  }
+
+  #[must_use]
+  pub fn len(&self) -> usize {
+    let (start, step) = (i128::from(self.start), i128::from(self.step));
+
+    let distance = if step > 0 {
+      self.end - start
+    } else {
+      start - self.end
+    };
+
+    if distance <= 0 {
+      return 0;
+    }
+
+    let step = step.abs();
+
+    usize::try_from((distance + step - 1) / step).unwrap_or(usize::MAX)
+  }
+}
+
+impl Display for Range {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    if self.step == 1 {
+      write!(f, "range({}, {})", self.start, self.end)
+    } else {
+      write!(f, "range({}, {}, {})", self.start, self.end, self.step)
+    }
+  }
+}
+
+impl PartialEq for Range {
+  fn eq(&self, other: &Self) -> bool {
+    let len = self.len();
+
+    len == other.len()
+      && (len == 0
+        || self.start == other.start && (len == 1 || self.step == other.step))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn contains() {
+    let range = Range::exclusive(10, 0, -3);
+
+    for (number, expected) in [
+      (10_i64, true),
+      (7, true),
+      (1, true),
+      (-2, false),
+      (8, false),
+      (13, false),
+    ] {
+      assert_eq!(range.contains(&Number::from(number)), expected, "{number}");
+    }
+
+    assert!(!range.contains(&Number::try_from("7.5").unwrap()));
+  }
+
+  #[test]
+  fn equality_compares_elements() {
+    assert_eq!(Range::exclusive(0, 10, 3), Range::exclusive(0, 11, 3));
+    assert_eq!(Range::exclusive(5, 0, 1), Range::exclusive(3, 3, 2));
+    assert_eq!(Range::inclusive(1, 1), Range::exclusive(1, 5, 10));
+    assert_ne!(Range::exclusive(0, 10, 1), Range::exclusive(0, 10, 2));
+  }
+
+  #[test]
+  fn len() {
+    assert_eq!(Range::exclusive(0, 10, 1).len(), 10);
+    assert_eq!(Range::exclusive(0, 10, 3).len(), 4);
+    assert_eq!(Range::exclusive(10, 0, -3).len(), 4);
+    assert_eq!(Range::exclusive(0, 10, -1).len(), 0);
+    assert_eq!(Range::inclusive(1, 5).len(), 5);
+    assert_eq!(Range::inclusive(i64::MAX, i64::MAX).len(), 1);
+  }
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SourceId(pub(crate) usize);
+
+impl SourceId {
+  #[must_use]
+  pub fn span(self, range: std::ops::Range<usize>) -> Span {
+    Span::new(self, range)
+  }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[derive(Clone, Debug, Default)]
+pub struct Sources {
+  files: Vec<(String, Source<Rc<str>>)>,
+}
+
+impl Sources {
+  pub fn add(&mut self, name: impl Into<String>, text: &str) -> SourceId {
+    self.files.push((name.into(), Source::from(Rc::from(text))));
+    SourceId(self.files.len() - 1)
+  }
+
+  #[must_use]
+  pub fn name(&self, id: SourceId) -> Option<&str> {
+    self.files.get(id.0).map(|(name, _)| name.as_str())
+  }
+}
+
+impl Cache<SourceId> for Sources {
+  type Storage = Rc<str>;
+
+  fn display<'a>(&self, id: &'a SourceId) -> Option<impl Display + 'a> {
+    self.name(*id).map(str::to_owned)
+  }
+
+  fn fetch(
+    &mut self,
+    id: &SourceId,
+  ) -> std::result::Result<&Source<Self::Storage>, impl fmt::Debug> {
+    self
+      .files
+      .get(id.0)
+      .map(|(_, source)| source)
+      .ok_or_else(|| format!("Unknown source {id:?}"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn add_returns_sequential_ids() {
+    let mut sources = Sources::default();
+
+    let (first, second) =
+      (sources.add("a.val", "1"), sources.add("<input>", "2"));
+
+    assert_eq!((first, second), (SourceId(0), SourceId(1)));
+    assert_eq!(sources.name(second), Some("<input>"));
+    assert_eq!(
+      sources.fetch(&first).unwrap().chars().collect::<String>(),
+      "1"
+    );
+    assert!(sources.fetch(&SourceId(2)).is_err());
+  }
+}
@@ -3,10 +3,16 @@ use super::*;
 #[derive(Clone, Debug)]
 pub enum Value<'src> {
   Boolean(bool),
+  Error(Rc<Exception<'src>>),
+  Fixed(Fixed),
   Function(Function<'src>),
   List(Vec<Self>),
+  Map(IndexMap<Key<'src>, Self>),
+  Matrix(Matrix),
   Null,
   Number(Number),
+  Polynomial(Polynomial),
+  Range(Range),
   String(Cow<'src, str>),
 }
 
@@ -19,10 +25,32 @@ impl<'a> Value<'a> {
     }
   }
 
+  pub(crate) fn contains(
+    &self,
+    needle: &Self,
+    needle_span: Span,
+    span: Span,
+  ) -> Result<bool, Error> {
+    match self {
+      Value::List(list) => Ok(list.contains(needle)),
+      Value::Map(map) => Ok(map.contains_key(&needle.key(needle_span)?)),
+      Value::Range(range) => {
+        Ok(matches!(needle, Value::Number(number) if range.contains(number)))
+      }
+      Value::String(string) => Ok(string.contains(needle.string(needle_span)?)),
+      value => Err(Error::new(
+        span,
+        format!("Cannot test membership in {}", value.type_name()),
+      )),
+    }
+  }
+
   #[must_use]
   pub fn display(&self, config: Config) -> String {
     match self {
       Value::Boolean(boolean) => boolean.to_string(),
+      Value::Error(exception) => exception.to_string(),
+      Value::Fixed(fixed) => fixed.to_string(),
       Value::Function(function) => format!("<function: {}>", function.name()),
       Value::List(list) => format!(
         "[{}]",
@@ -35,27 +63,130 @@ impl<'a> Value<'a> {
           .collect::<Vec<_>>()
           .join(", ")
       ),
+      Value::Map(map) => format!(
+        "{{{}}}",
+        map
+          .iter()
+          .map(|(key, value)| format!(
+            "{}: {}",
+            key.display(config),
+            match value {
+              Value::String(string) => format!("\'{string}\'"),
+              _ => value.display(config),
+            }
+          ))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+      Value::Matrix(matrix) => matrix.display(config),
       Value::Null => "null".into(),
       Value::Number(number) => number.display(config),
+      Value::Polynomial(polynomial) => polynomial.display(config),
+      Value::Range(range) => range.to_string(),
       Value::String(string) => string.to_string(),
     }
   }
 
+  pub(crate) fn integer(&self, span: Span) -> Result<Integer, Error> {
+    if let Value::Fixed(fixed) = self {
+      return Ok(fixed.to_integer());
+    }
+
+    self
+      .number(span)?
+      .to_integer()
+      .ok_or_else(|| Error::new(span, format!("'{self}' is not an integer")))
+  }
+
   pub(crate) fn into_list(self, span: Span) -> Result<Vec<Value<'a>>, Error> {
     match self {
       Value::List(x) => Ok(x),
+      Value::Matrix(matrix) => Ok(matrix.to_list()),
+      Value::Range(range) => Ok(range.iter().map(Value::Number).collect()),
       value => Err(Error::new(span, format!("'{value}' is not a list"))),
     }
   }
 
-  pub(crate) fn list(&self, span: Span) -> Result<&[Value<'a>], Error> {
-    if let Value::List(x) = self {
+  pub(crate) fn key(&self, span: Span) -> Result<Key<'a>, Error> {
+    match self {
+      Value::Boolean(boolean) => Ok(Key::Boolean(*boolean)),
+      Value::Fixed(fixed) => Ok(Key::Number(Number::from(*fixed))),
+      Value::Null => Ok(Key::Null),
+      Value::Number(number) if number.is_nan() => Err(Error::new(
+        span,
+        format!("Cannot use {number} as a map key"),
+      )),
+      Value::Number(Number::Interval(number)) if !number.is_point() => Err(
+        Error::new(span, format!("Cannot use interval {self} as a map key")),
+      ),
+      Value::Number(number) => Ok(Key::Number(number.clone())),
+      Value::String(string) => Ok(Key::String(string.clone())),
+      Value::Error(_)
+      | Value::Function(_)
+      | Value::List(_)
+      | Value::Map(_)
+      | Value::Matrix(_)
+      | Value::Polynomial(_)
+      | Value::Range(_) => Err(Error::new(
+        span,
+        format!("Cannot use {} as a map key", self.type_name()),
+      )),
+    }
+  }
+
+  pub(crate) fn list(&self, span: Span) -> Result<Cow<'_, [Value<'a>]>, Error> {
+    match self {
+      Value::List(x) => Ok(Cow::Borrowed(x)),
+      Value::Matrix(matrix) => Ok(Cow::Owned(matrix.to_list())),
+      Value::Range(range) => {
+        Ok(Cow::Owned(range.iter().map(Value::Number).collect()))
+      }
+      value => Err(Error::new(span, format!("'{value}' is not a list"))),
+    }
+  }
+
+  pub(crate) fn map(
+    &self,
+    span: Span,
+  ) -> Result<&IndexMap<Key<'a>, Value<'a>>, Error> {
+    if let Value::Map(x) = self {
       Ok(x)
     } else {
-      Err(Error::new(span, format!("'{self}' is not a list")))
+      Err(Error::new(span, format!("'{self}' is not a map")))
     }
   }
 
+  pub(crate) fn matrix(&self, span: Span) -> Result<Cow<'_, Matrix>, Error> {
+    let rows = match self {
+      Value::Matrix(matrix) => return Ok(Cow::Borrowed(matrix)),
+      Value::List(items)
+        if items.iter().all(|item| matches!(item, Value::List(_))) =>
+      {
+        items
+          .iter()
+          .map(|row| {
+            row
+              .list(span)?
+              .iter()
+              .map(|entry| entry.number(span).cloned())
+              .collect()
+          })
+          .collect::<Result<Vec<Vec<Number>>, Error>>()?
+      }
+      Value::List(items) => items
+        .iter()
+        .map(|item| Ok(vec![item.number(span)?.clone()]))
+        .collect::<Result<Vec<Vec<Number>>, Error>>()?,
+      value => {
+        return Err(Error::new(span, format!("'{value}' is not a matrix")));
+      }
+    };
+
+    Matrix::from_rows(rows)
+      .map(Cow::Owned)
+      .map_err(|error| error.with_span(span))
+  }
+
   pub(crate) fn number(&self, span: Span) -> Result<&Number, Error> {
     if let Value::Number(x) = self {
       Ok(x)
@@ -64,6 +195,25 @@ impl<'a> Value<'a> {
     }
   }
 
+  pub(crate) fn polynomial(
+    &self,
+    span: Span,
+  ) -> Result<Cow<'_, Polynomial>, Error> {
+    match self {
+      Value::List(items) => Ok(Cow::Owned(Polynomial::new(
+        items
+          .iter()
+          .map(|item| item.number(span).cloned())
+          .collect::<Result<Vec<Number>, Error>>()?,
+      ))),
+      Value::Number(number) => {
+        Ok(Cow::Owned(Polynomial::constant(number.clone())))
+      }
+      Value::Polynomial(polynomial) => Ok(Cow::Borrowed(polynomial)),
+      value => Err(Error::new(span, format!("'{value}' is not a polynomial"))),
+    }
+  }
+
   pub(crate) fn string(&self, span: Span) -> Result<&str, Error> {
     if let Value::String(x) = self {
       Ok(x.as_ref())
@@ -75,10 +225,16 @@ impl<'a> Value<'a> {
   pub(crate) fn type_name(&self) -> &'static str {
     match self {
       Value::Boolean(_) => "boolean",
+      Value::Error(_) => "error",
+      Value::Fixed(fixed) => fixed.ty().name(),
       Value::Function(_) => "function",
       Value::List(_) => "list",
+      Value::Map(_) => "map",
+      Value::Matrix(_) => "matrix",
       Value::Null => "null",
       Value::Number(_) => "number",
+      Value::Polynomial(_) => "polynomial",
+      Value::Range(_) => "range",
       Value::String(_) => "string",
     }
   }
@@ -94,12 +250,20 @@ impl PartialEq for Value<'_> {
   fn eq(&self, other: &Self) -> bool {
     match (self, other) {
       (Value::Boolean(a), Value::Boolean(b)) => a == b,
+      (Value::Error(a), Value::Error(b)) => a == b,
+      (Value::Fixed(a), Value::Fixed(b)) => a == b,
+      (Value::Fixed(a), Value::Number(b))
+      | (Value::Number(b), Value::Fixed(a)) => Number::from(*a) == *b,
       (Value::Function(a), Value::Function(b)) => a == b,
       (Value::List(a), Value::List(b)) => {
         a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a == b)
       }
+      (Value::Map(a), Value::Map(b)) => a == b,
+      (Value::Matrix(a), Value::Matrix(b)) => a == b,
       (Value::Null, Value::Null) => true,
       (Value::Number(a), Value::Number(b)) => a == b,
+      (Value::Polynomial(a), Value::Polynomial(b)) => a == b,
+      (Value::Range(a), Value::Range(b)) => a == b,
       (Value::String(a), Value::String(b)) => a == b,
       _ => false,
     }
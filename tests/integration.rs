@@ -3,7 +3,12 @@ use {
   executable_path::executable_path,
   indoc::indoc,
   pretty_assertions::assert_eq,
-  std::{fs::File, io::Write, process::Command, str},
+  std::{
+    fs::{self, File},
+    io::Write,
+    process::Command,
+    str,
+  },
   tempfile::TempDir,
   unindent::Unindent,
 };
@@ -22,6 +27,7 @@ struct Test<'a> {
   expected_status: i32,
   expected_stderr: Match<'a>,
   expected_stdout: Match<'a>,
+  files: Vec<(&'a str, &'a str)>,
   program: &'a str,
   tempdir: TempDir,
 }
@@ -53,12 +59,18 @@ impl<'a> Test<'a> {
     }
   }
 
+  fn file(mut self, path: &'a str, content: &'a str) -> Self {
+    self.files.push((path, content));
+    self
+  }
+
   fn new() -> Result<Self> {
     Ok(Self {
       arguments: Vec::new(),
       expected_status: 0,
       expected_stderr: Match::Empty,
       expected_stdout: Match::Empty,
+      files: Vec::new(),
       program: "",
       tempdir: TempDir::new()?,
     })
@@ -75,6 +87,16 @@ impl<'a> Test<'a> {
   fn run_and_return_tempdir(self) -> Result<TempDir> {
     let mut command = Command::new(executable_path(env!("CARGO_PKG_NAME")));
 
+    for (path, content) in &self.files {
+      let path = self.tempdir.path().join(path);
+
+      if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+      }
+
+      fs::write(path, content.unindent())?;
+    }
+
     let program_path = self.tempdir.path().join("program.val");
 
     let mut file = File::create(&program_path)?;
@@ -167,6 +189,16 @@ fn absolute_value() -> Result {
     .run()
 }
 
+#[test]
+fn absolute_value_bars() -> Result {
+  Test::new()?
+    .program(
+      "println(|3 - 5|, |-2.5|, |2 - 5| - 1, ||-3| - 5|, |i8(-5)|, |sqrt(2) - 2|)",
+    )
+    .expected_stdout(Exact("2 2.5 2 2 5i8 0.585786437626905\n"))
+    .run()
+}
+
 #[test]
 fn addition() -> Result {
   Test::new()?
@@ -228,10 +260,8 @@ fn arccosecant() -> Result {
 
   Test::new()?
     .program("println(acsc(0.5))")
-    .expected_status(1)
-    .expected_stderr(Contains(
-      "acsc argument must have absolute value at least 1",
-    ))
+    .expected_status(0)
+    .expected_stdout(Exact("1.570796326794897+1.316957896924817i\n"))
     .run()
 }
 
@@ -257,8 +287,8 @@ fn arccosine() -> Result {
 
   Test::new()?
     .program("println(acos(-2))")
-    .expected_status(1)
-    .expected_stderr(Contains("acos argument must be between -1 and 1"))
+    .expected_status(0)
+    .expected_stdout(Exact("3.141592653589793-1.316957896924817i\n"))
     .run()
 }
 
@@ -293,10 +323,8 @@ fn arcsecant() -> Result {
 
   Test::new()?
     .program("println(asec(0.5))")
-    .expected_status(1)
-    .expected_stderr(Contains(
-      "asec argument must have absolute value at least 1",
-    ))
+    .expected_status(0)
+    .expected_stdout(Exact("-1.316957896924817i\n"))
     .run()
 }
 
@@ -322,8 +350,8 @@ fn arcsine() -> Result {
 
   Test::new()?
     .program("println(asin(2))")
-    .expected_status(1)
-    .expected_stderr(Contains("asin argument must be between -1 and 1"))
+    .expected_status(0)
+    .expected_stdout(Exact("1.570796326794897+1.316957896924817i\n"))
     .run()
 }
 
@@ -375,6 +403,89 @@ fn assignment() -> Result {
     .run()
 }
 
+#[test]
+fn bit_manipulation_builtins() -> Result {
+  Test::new()?
+    .program(
+      "
+      println(popcount(0xff), popcount(0))
+      println(bit_length(255), bit_length(256), bit_length(-256), bit_length(0))
+      println(trailing_zeros(40), trailing_zeros(-8))
+      println(test_bit(5, 0), test_bit(5, 1), test_bit(-1, 1000))
+      println(set_bit(0, 10), set_bit(15, 0, false), set_bit(-1, 3, false))
+      ",
+    )
+    .expected_stdout(Exact("8 0\n8 9 9 0\n3 3\ntrue false true\n1024 14 -9\n"))
+    .run()?;
+
+  for (program, message) in [
+    (
+      "popcount(-1)",
+      "Argument to `popcount` must be a non-negative integer",
+    ),
+    (
+      "trailing_zeros(0)",
+      "Argument to `trailing_zeros` must be a non-zero integer",
+    ),
+    (
+      "test_bit(1, -1)",
+      "Bit index to `test_bit` must be between 0 and 4294967295",
+    ),
+    ("set_bit(1.5, 0)", "'1.5' is not an integer"),
+  ] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains(message))
+      .run()?;
+  }
+
+  Ok(())
+}
+
+#[test]
+fn bitwise_operators() -> Result {
+  Test::new()?
+    .program(
+      "
+      println(12 & 10, 12 | 3, 12 ~ 10, ~5, ~-1)
+      println(-6 & 255, -6 | 1, -6 ~ -1)
+      println(1 << 100, 1024 >> 3, -17 >> 2)
+      println(1 + 2 << 3, 6 & 3 == 2, 0xf0 | 0x0f == 0xff)
+
+      flags = 0xff
+      flags &= 0x0f
+      flags <<= 4
+      flags |= 1
+      flags ~= 3
+      flags >>= 1
+      println(flags)
+      ",
+    )
+    .expected_stdout(Exact(
+      "8 15 6 -6 0\n250 -5 5\n1267650600228229401496703205376 128 -5\n24 true true\n121\n",
+    ))
+    .run()
+}
+
+#[test]
+fn bitwise_operators_require_integers() -> Result {
+  for (program, message) in [
+    ("1.5 & 1", "'1.5' is not an integer"),
+    ("1 | 'a'", "'a' is not a number"),
+    ("~0.5", "'0.5' is not an integer"),
+    ("1 << -1", "Shift amount must be between 0 and 4294967295"),
+  ] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains(message))
+      .run()?;
+  }
+
+  Ok(())
+}
+
 #[test]
 fn boolean_comparison() -> Result {
   Test::new()?
@@ -641,6 +752,35 @@ fn cannot_return_outside_of_function() -> Result {
     .run()
 }
 
+#[test]
+fn caught_error_values() -> Result {
+  Test::new()?
+    .program(
+      "
+      try {
+        x = 10 / 0
+      } catch (e) {
+        println(e.kind, e.message, e.span, e.value)
+        println(e)
+      }
+
+      try { int('abc') } catch (e) { println(e.kind) }
+      try { 5 % 0 } catch (e) { println(e.kind) }
+      try { 0 ^ -1 } catch (e) { println(e.kind) }
+      ",
+    )
+    .expected_stdout(Exact(
+      "division_by_zero division by zero [17, 18] null\n<error: division by zero>\nerror\nmodulo_by_zero\nzero_to_negative_power\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("try { throw 1 } catch (e) { println(e.stack) }")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot access `stack` on error"))
+    .run()
+}
+
 #[test]
 fn ceiling_function() -> Result {
   Test::new()?
@@ -662,6 +802,21 @@ fn ceiling_function() -> Result {
     .run()
 }
 
+#[test]
+fn chained_comparisons() -> Result {
+  Test::new()?
+    .program(
+      "
+      mid = fn() { println(\"mid\"); return 5 }
+      println(1 < mid() <= 5)
+      println(3 < 2 < mid())
+      println(\"a\" < \"b\" < \"c\", 1 < 2 < 3 == true)
+      ",
+    )
+    .expected_stdout(Exact("mid\ntrue\nfalse\ntrue true\n"))
+    .run()
+}
+
 #[test]
 fn combined_operations() -> Result {
   Test::new()?
@@ -748,6 +903,160 @@ fn comparison_with_expressions() -> Result {
     .run()
 }
 
+#[test]
+fn complex_numbers() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      println(sqrt(-4), i^2, (1 + 2i) * (3 - i), (1 + 2i) / (3 - 4i))
+      println(sqrt(3 + 4i), (1 + i)^10, (1 + i)^-2, 2 - 0i)
+      println(re(2 + 5i), im(2 + 5i), conj(2 + 5i), abs(3 + 4i), arg(-1))
+      println(1 + 2i == 1 + 2i, i == 1, {3i: 'x'}[3i])
+      "
+    })
+    .expected_stdout(Exact(
+      "2i -1 5+5i -0.2+0.4i\n2+i 32i -0.5i 2\n2 5 2-5i 5 3.141592653589793\ntrue false x\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println(ln(-2), cos(1 + i))")
+    .expected_stdout(Exact(
+      "0.6931471805599453+3.141592653589793i 0.833730025131149-0.9888977057628651i\n",
+    ))
+    .run()?;
+
+  for program in ["println(i < 2)", "println(2 < i)", "println(0 < i < 2)"] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains("Cannot order complex number i with '<'"))
+      .run()?;
+  }
+
+  Test::new()?
+    .program("println(5 % i)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot take the remainder of a complex number"))
+    .run()?;
+
+  Test::new()?
+    .program("println(exp(1 + i), exp(2) == e(2), arc(i), arc(-i))")
+    .expected_stdout(Exact(
+      "1.468693939915885+2.287355287178842i true inf*i -inf*i\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println((1 + i)!)")
+    .expected_status(1)
+    .expected_stderr(Contains("Factorial is undefined for complex number 1+i"))
+    .run()?;
+
+  Test::new()?
+    .program("println(int(1 + 2i))")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot convert complex number 1+2i to int"))
+    .run()
+}
+
+#[test]
+fn compound_assignment() -> Result {
+  Test::new()?
+    .program(
+      "
+      total = 0
+      for x in [1, 2, 3] { total += x }
+      a = 10; a -= 4; a *= 3; a /= 4; b = 7; b %= 4; c = 2; c ^= 10
+      s = 'n = '; s += total
+      println(total, a, b, c, s)
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("6 4.5 3 1024 n = 6\n"))
+    .run()?;
+
+  Test::new()?
+    .program(
+      "
+      grid = [[0, 0], [0, 0]]
+      grid[1][0] += 5
+      m = {'counts': [1, 2]}
+      m['counts'][1] *= 10
+      println(grid, m)
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("[[0, 0], [5, 0]] {'counts': [1, 20]}\n"))
+    .run()
+}
+
+#[test]
+fn compound_assignment_errors() -> Result {
+  Test::new()?
+    .program("x += 1")
+    .expected_status(1)
+    .expected_stderr(Contains("Undefined variable `x`"))
+    .run()?;
+
+  Test::new()?
+    .program("m = {}; m['a'] += 1")
+    .expected_status(1)
+    .expected_stderr(Contains("Key 'a' not found in map"))
+    .run()?;
+
+  Test::new()?
+    .program("x = 1; x /= 0")
+    .expected_status(1)
+    .expected_stderr(Contains("division by zero"))
+    .run()
+}
+
+#[test]
+fn compound_assignment_evaluates_indices_once() -> Result {
+  Test::new()?
+    .program(
+      "
+      calls = 0
+      fn next() { calls += 1; return 0 }
+      xs = [1, 2]
+      xs[next()] += 10
+      println(xs, calls)
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("[11, 2] 1\n"))
+    .run()
+}
+
+#[test]
+fn conditional_expression() -> Result {
+  Test::new()?
+    .program(
+      "
+      x = 5
+      size = if (x > 3) 'big' else 'small'
+      sign = x > 0 ? 1 : x < 0 ? -1 : 0
+      println(size, sign, if (x == 1) 'one' else if (x == 5) 'five' else 'other')
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("big 1 five\n"))
+    .run()?;
+
+  Test::new()?
+    .program("println(true ? 1 : undefined, if (false) undefined else 2)")
+    .expected_status(0)
+    .expected_stdout(Exact("1 2\n"))
+    .run()?;
+
+  Test::new()?
+    .program("1 ? 2 : 3")
+    .expected_status(1)
+    .expected_stderr(Contains("'1' is not a boolean"))
+    .run()
+}
+
 #[test]
 fn configured_digits() -> Result {
   #[track_caller]
@@ -787,6 +1096,63 @@ fn configured_digits_in_concatenation() -> Result {
     .run()
 }
 
+#[test]
+fn const_bindings() -> Result {
+  Test::new()?
+    .program(
+      "
+      const [low, high] = [1, 10]
+      fn clamp(x) {
+        if (x > high) { return high }
+        return x
+      }
+      println(clamp(42))
+
+      fn shadow() {
+        const high = 5
+        return high
+      }
+
+      println(shadow(), high)
+      ",
+    )
+    .expected_stdout(Exact("10\n5 10\n"))
+    .run()?;
+
+  for program in [
+    "const limit = 3\nlimit = 4",
+    "const limit = 3\nlimit += 1",
+    "const limit = [3]\nlimit[0] = 4",
+    "const limit = 3\nfn f() { limit = 4 }\nf()",
+  ] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains("Cannot assign to constant `limit`"))
+      .run()?;
+  }
+
+  Ok(())
+}
+
+#[test]
+fn const_cannot_be_rebound_by_for_loop() -> Result {
+  Test::new()?
+    .program("const x = 1\nfor x in [5] {}\nx = 3\nprintln(x)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot assign to constant `x`"))
+    .run()
+}
+
+#[test]
+fn const_cannot_be_redeclared_with_let() -> Result {
+  Test::new()?
+    .program("const x = 1\nlet x = 2\nx = 7")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot assign to constant `x`"))
+    .run()
+}
+
 #[test]
 fn continue_in_if_outside_loop() -> Result {
   Test::new()?
@@ -934,6 +1300,76 @@ fn cotangent() -> Result {
     .run()
 }
 
+#[test]
+fn default_parameters() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn power(x, exponent = 2) { return x ^ exponent }
+      fn scale(x, factor = x * 2) { return factor }
+      println(power(3), power(2, 10), scale(3), scale(3, 1))
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("9 1024 6 1\n"))
+    .run()?;
+
+  Test::new()?
+    .program("fn f(x, base = 10) {}\nf(1, 2, 3)")
+    .expected_status(1)
+    .expected_stderr(Contains("Function `f` expects 1 or 2 arguments, got 3"))
+    .run()?;
+
+  Test::new()?
+    .program("fn f(x, y = 1, z = 2) {}\nf()")
+    .expected_status(1)
+    .expected_stderr(Contains("Function `f` expects 1 to 3 arguments, got 0"))
+    .run()
+}
+
+#[test]
+fn destructuring_assignment() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn divmod(a, b) { return [floor(a / b), a % b] }
+      [q, r] = divmod(17, 5)
+      [x, [y, z]] = [1, [2, 3]]
+      a = 'a'; b = 'b'
+      [a, b] = [b, a]
+      xs = [0, 0]
+      [xs[1], xs[0]] = [8, 9]
+      println(q, r, x, y, z, a, b, xs)
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("3 2 1 2 3 b a [9, 8]\n"))
+    .run()
+}
+
+#[test]
+fn destructuring_shape_mismatch() -> Result {
+  Test::new()?
+    .program("[a, b] = [1, 2, 3]")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot destructure list of length 3 into 2 targets",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("[a, [b]] = [1, 2]")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot destructure number into 1 target"))
+    .run()?;
+
+  Test::new()?
+    .program("[a, b] += [1, 2]")
+    .expected_status(1)
+    .expected_stderr(Contains("found '=' expected"))
+    .run()
+}
+
 #[test]
 fn direct_recursive_function() -> Result {
   Test::new()?
@@ -999,6 +1435,43 @@ fn element_assignment_on_non_list_errors() -> Result {
     .run()
 }
 
+#[test]
+fn else_if_chain() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn classify(n) {
+        if (n < 0) {
+          return 'negative'
+        } else if (n == 0) {
+          return 'zero'
+        } else if (n < 10) {
+          return 'small'
+        } else {
+          return 'large'
+        }
+      }
+
+      println(classify(-1), classify(0), classify(5), classify(50))
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("negative zero small large\n"))
+    .run()?;
+
+  Test::new()?
+    .program(
+      "
+      x = 3
+      if (x == 1) { println('one') } else if (x == 2) { println('two') }
+      println('done')
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("done\n"))
+    .run()
+}
+
 #[test]
 fn equal_to() -> Result {
   Test::new()?
@@ -1066,6 +1539,24 @@ fn exact_rational_arithmetic() -> Result {
     .run()
 }
 
+#[test]
+fn exit_is_not_catchable() -> Result {
+  Test::new()?
+    .program(
+      "
+      try {
+        exit(3)
+      } catch (e) {
+        println('caught')
+      } finally {
+        println('finally')
+      }
+      ",
+    )
+    .expected_status(3)
+    .run()
+}
+
 #[test]
 fn exit_or_quit() -> Result {
   Test::new()?.program("exit()").expected_status(0).run()?;
@@ -1091,6 +1582,34 @@ fn exit_or_quit() -> Result {
     .run()
 }
 
+#[test]
+fn factorial() -> Result {
+  Test::new()?
+    .program("println(5!, 0!, 25!, (2.5)!, u8(5)!)")
+    .expected_stdout(Exact(
+      "120 1 15511210043330985984000000 3.323350970447843 120\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println((-3)!)")
+    .expected_status(1)
+    .expected_stderr(Contains("Factorial is undefined for negative integer -3"))
+    .run()?;
+
+  Test::new()?
+    .program("println(100000000!)")
+    .expected_status(1)
+    .expected_stderr(Contains("Factorial of 100000000 is too large"))
+    .run()?;
+
+  Test::new()?
+    .program("println(factorial(1e9))")
+    .expected_status(1)
+    .expected_stderr(Contains("Factorial of 1000000000 is too large"))
+    .run()
+}
+
 #[test]
 fn fibonacci_function() -> Result {
   Test::new()?
@@ -1150,6 +1669,79 @@ fn finding_first_even_number() -> Result {
     .run()
 }
 
+#[test]
+fn fixed_width_integer_errors() -> Result {
+  for (program, message) in [
+    (
+      "u8(255, 'checked') + 1",
+      "Integer overflow in u8 arithmetic",
+    ),
+    ("-i8(-128, 'checked')", "Integer overflow in i8 arithmetic"),
+    ("u8(300, 'checked')", "300 does not fit in u8"),
+    (
+      "u8(1) + i8(1)",
+      "Cannot mix u8 and i8 operands, convert one with `u8(x)`",
+    ),
+    ("u8(1.5)", "'1.5' is not an integer"),
+    ("u8(1, 'panic')", "Unknown overflow mode: panic"),
+    ("u16(1) / 0", "division by zero"),
+    ("i32(2) ^ -1", "Cannot raise i32 to a negative power"),
+    (
+      "abs(i8(-128, 'checked'))",
+      "Integer overflow in i8 arithmetic",
+    ),
+    (
+      "u8(1, 'checked') + u8(255)",
+      "Integer overflow in u8 arithmetic",
+    ),
+    (
+      "u8(255) + u8(1, 'checked')",
+      "Integer overflow in u8 arithmetic",
+    ),
+  ] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains(message))
+      .run()?;
+  }
+
+  Test::new()?
+    .argument("--overflow")
+    .argument("checked")
+    .program("println(u8(255, 'wrapping') + 1)\nu8(255) + 1")
+    .expected_status(1)
+    .expected_stdout(Exact("0u8\n"))
+    .expected_stderr(Contains("Integer overflow in u8 arithmetic"))
+    .run()
+}
+
+#[test]
+fn fixed_width_integers() -> Result {
+  Test::new()?
+    .program(
+      "
+      x = u8(250)
+      println(x, x + 10, x * 2, 3 - x, u8(300), i8(200), i8(x))
+      println(i32(-7) / 2, i32(-7) % 2, u32(1) << 32, i64(2) ^ 63)
+      println(u8(0xf0) & 0x3c, u8(0xf0) | 1, ~u8(0), ~i16(0), -i8(-128))
+
+      s = u8(250, 'saturating')
+      s += 10
+      println(s, s - 300, u8(s, 'checked'), i8(-100, 'saturating') * 2)
+
+      println(u8(5) == 5, u8(5) < 6, i8(-1) < u8(0), int(x) + 1000)
+      println(match (x) { n: u8 => 'byte', _ => 'other' }, {u8(1): 'a'}[1])
+      println(abs(i8(-5)), abs(u8(4)), sqrt(u8(4)), |i8(-5)|, √u8(9))
+      println(s + u8(10), u8(10) + s)
+      ",
+    )
+    .expected_stdout(Exact(
+      "250u8 4u8 244u8 9u8 44u8 -56i8 -6i8\n-3i32 -1i32 0u32 -9223372036854775808i64\n48u8 241u8 255u8 -1i16 -128i8\n255u8 0u8 255u8 -128i8\ntrue true true 1250\nbyte a\n5i8 4u8 2 5i8 3\n255u8 255u8\n",
+    ))
+    .run()
+}
+
 #[test]
 fn float_literals() -> Result {
   Test::new()?
@@ -1249,6 +1841,33 @@ fn for_loop_over_range() -> Result {
     .run()
 }
 
+#[test]
+fn for_loop_over_range_expression() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      result = []
+
+      for i in 1..=3 {
+        result = append(result, i)
+      }
+
+      for i in range(0, 9223372036854775807) {
+        if (i == 2) {
+          break
+        }
+
+        result = append(result, i)
+      }
+
+      println(result)
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact("[1, 2, 3, 0, 1]\n"))
+    .run()
+}
+
 #[test]
 fn for_loop_over_range_with_step() -> Result {
   Test::new()?
@@ -1293,6 +1912,29 @@ fn for_loop_with_break_and_continue() -> Result {
     .run()
 }
 
+#[test]
+fn for_loop_with_destructuring() -> Result {
+  Test::new()?
+    .program(
+      "
+      for [name, [x, y]] in [['a', [1, 2]], ['b', [3, 4]]] {
+        println(name, x + y)
+      }
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("a 3\nb 7\n"))
+    .run()?;
+
+  Test::new()?
+    .program("for [a, b] in [[1, 2], [3]] {}")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot destructure list of length 1 into 2 targets",
+    ))
+    .run()
+}
+
 #[test]
 fn function_arity_is_checked_before_arguments() -> Result {
   Test::new()?
@@ -1426,6 +2068,25 @@ fn function_observes_outer_scope_changes() -> Result {
     .run()
 }
 
+#[test]
+fn function_parameter_errors() -> Result {
+  Test::new()?
+    .program("fn f(a = 1, b) {}")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Required parameter `b` cannot follow a parameter with a default value",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("fn f(...a, b) {}")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Rest parameter `...a` must be the last parameter",
+    ))
+    .run()
+}
+
 #[test]
 fn function_returned_closure_keeps_scope() -> Result {
   Test::new()?
@@ -1923,6 +2584,16 @@ fn if_with_while_loop() -> Result {
     .run()
 }
 
+#[test]
+fn implicit_multiplication() -> Result {
+  Test::new()?
+    .program(
+      "x = 3; println(2x, 2x^2, 3(x + 1), 1/2x, 2pi == 2 * pi, -2x, 2x!)",
+    )
+    .expected_stdout(Exact("6 18 12 0.1666666666666667 true -6 12\n"))
+    .run()
+}
+
 #[test]
 fn implicit_return() -> Result {
   Test::new()?
@@ -1947,6 +2618,158 @@ fn implicit_return() -> Result {
     .run()
 }
 
+#[test]
+fn import_binds_module_namespace() -> Result {
+  Test::new()?
+    .file(
+      "geometry.val",
+      "
+      pi_ish = 3
+      fn area(r) { return pi_ish * r * r }
+      ",
+    )
+    .program(
+      "
+      import \"geometry.val\"
+      println(geometry.area(2))
+      println(geometry.pi_ish)
+      println(geometry)
+      ",
+    )
+    .expected_stdout(Contains("12\n3\n{'area': "))
+    .run()
+}
+
+#[test]
+fn import_cycle() -> Result {
+  Test::new()?
+    .file("a.val", "import \"b.val\"")
+    .file("b.val", "import \"a.val\"")
+    .program("import \"a.val\"")
+    .expected_status(1)
+    .expected_stderr(Contains("Import cycle detected: a.val -> b.val -> a.val"))
+    .run()
+}
+
+#[test]
+fn import_cycle_through_entry_file() -> Result {
+  Test::new()?
+    .file("b.val", "import \"program.val\"")
+    .program("println('entry')\nimport \"b.val\"")
+    .expected_status(1)
+    .expected_stdout(Exact("entry\n"))
+    .expected_stderr(Contains(
+      "Import cycle detected: program.val -> b.val -> program.val",
+    ))
+    .run()
+}
+
+#[test]
+fn import_errors_are_reported_in_module() -> Result {
+  Test::new()?
+    .file("lib/broken.val", "x = 1\ny = x / 0")
+    .program("import \"lib/broken.val\"")
+    .expected_status(1)
+    .expected_stderr(Contains("lib/broken.val:2:"))
+    .run()?;
+
+  Test::new()?
+    .file("syntax.val", "x = (1")
+    .program("import \"syntax.val\"")
+    .expected_status(1)
+    .expected_stderr(Contains("syntax.val:1:"))
+    .run()
+}
+
+#[test]
+fn import_evaluates_module_once() -> Result {
+  Test::new()?
+    .file("counter.val", "println('loading')\nvalue = 1")
+    .file(
+      "other.val",
+      "import \"counter.val\"\nvalue = counter.value + 1",
+    )
+    .program(
+      "
+      import \"counter.val\"
+      import \"other.val\"
+      import \"counter.val\" as again
+      println(other.value, again.value)
+      ",
+    )
+    .expected_stdout(Exact("loading\n2 1\n"))
+    .run()
+}
+
+#[test]
+fn import_exit_stops_program() -> Result {
+  Test::new()?
+    .file("quit.val", "exit(3)")
+    .program("import \"quit.val\"\nprintln('unreachable')")
+    .expected_status(3)
+    .run()
+}
+
+#[test]
+fn import_function_errors_are_reported_in_module() -> Result {
+  Test::new()?
+    .file("lib.val", "fn boom(x) {\n  return x / 0\n}")
+    .program("import \"lib.val\"\n\nprintln(lib.boom(1))")
+    .expected_status(1)
+    .expected_stderr(Contains("lib.val:2:14"))
+    .run()
+}
+
+#[test]
+fn import_missing_file() -> Result {
+  Test::new()?
+    .program("import \"missing.val\"")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot import `missing.val`"))
+    .run()
+}
+
+#[test]
+fn import_module_scope_is_isolated() -> Result {
+  Test::new()?
+    .file("module.val", "println(secret)")
+    .program("secret = 1\nimport \"module.val\"")
+    .expected_status(1)
+    .expected_stderr(Contains("Undefined variable `secret`"))
+    .run()
+}
+
+#[test]
+fn import_resolves_relative_to_importing_file() -> Result {
+  Test::new()?
+    .file(
+      "lib/math.val",
+      "import \"helpers/double.val\" as d\nfour = d.twice(2)",
+    )
+    .file("lib/helpers/double.val", "fn twice(x) { return x * 2 }")
+    .program("import \"lib/math.val\" as m\nprintln(m.four)")
+    .expected_stdout(Exact("4\n"))
+    .run()
+}
+
+#[test]
+fn import_with_invalid_module_name() -> Result {
+  Test::new()?
+    .file("my-lib.val", "x = 1")
+    .program("import \"my-lib.val\"")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot derive a module name from `my-lib.val`, use `as` to name it",
+    ))
+    .run()?;
+
+  Test::new()?
+    .file("my-lib.val", "x = 1")
+    .program("import \"my-lib.val\" as lib\nprintln(lib.x)")
+    .expected_stdout(Exact("1\n"))
+    .run()
+}
+
 #[test]
 fn infinite_loop_with_return() -> Result {
   Test::new()?
@@ -1983,6 +2806,132 @@ fn integer_literals() -> Result {
     .run()
 }
 
+#[test]
+fn interpolated_strings() -> Result {
+  Test::new()?
+    .program(indoc! {
+      r#"
+      fn fib(n) {
+        if (n <= 1) {
+          return n
+        }
+
+        return fib(n - 1) + fib(n - 2)
+      }
+
+      for i in range(5, 7) {
+        println(f"fib({i}) = {fib(i)}")
+      }
+
+      m = {'a': [1, 'b']}
+      println(f'{m["a"]} {{literal}} {1 / 3}\t{null}')
+      "#
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "fib(5) = 5\nfib(6) = 8\n[1, 'b'] {literal} 0.3333333333333333\tnull\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .argument("--digits")
+    .argument("3")
+    .program("println(f'{pi}')")
+    .expected_status(0)
+    .expected_stdout(Exact("3.14\n"))
+    .run()?;
+
+  Test::new()?
+    .program("println(f'{undefined}')")
+    .expected_status(1)
+    .expected_stderr(Contains("Undefined variable `undefined`"))
+    .run()
+}
+
+#[test]
+fn interval_arithmetic() -> Result {
+  Test::new()?
+    .argument("-p")
+    .argument("53")
+    .program(indoc! {
+      "
+      x = interval(1, 2)
+      println(x, x + 1, x * interval(-1, 3), 1 / x, x^2, -x)
+      println(lower(x), upper(x), sqrt(interval(2)), sin(interval(1, 2)))
+      println(interval(1, 2) < interval(3, 4), interval(5) == 5)
+      "
+    })
+    .expected_stdout(Exact(
+      "1.5 ± 0.5 2.5 ± 0.5 2 ± 4 0.75 ± 0.25 2.5 ± 1.5 -1.5 ± 0.5\n1 2 1.414213562373095 ± 1.2e-16 0.9207354924039483 ± 0.08\ntrue true\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println(interval(2, 1))")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot make an interval from 2 to 1"))
+    .run()?;
+
+  Test::new()?
+    .program("println(1 / interval(-1, 1))")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot divide by an interval containing zero"))
+    .run()?;
+
+  Test::new()?
+    .program("println(interval(1, 3) < interval(2, 4))")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot order overlapping intervals 2 ± 1 and 3 ± 1 with '<'",
+    ))
+    .run()?;
+
+  for (program, message) in [
+    (
+      "sqrt(interval(-1, 4))",
+      "Square root is undefined on part of interval 1.5 ± 2.5",
+    ),
+    (
+      "ln(interval(-1, 2))",
+      "Logarithm is undefined on part of interval 0.5 ± 1.5",
+    ),
+    (
+      "asin(interval(0, 2))",
+      "Arcsine is undefined on part of interval 1 ± 1",
+    ),
+    (
+      "interval(0, 2)!",
+      "Factorial of interval 1 ± 1 needs a lower bound of at least 1",
+    ),
+  ] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains(message))
+      .run()?;
+  }
+
+  Test::new()?
+    .argument("-p")
+    .argument("53")
+    .program("println(interval(2, 3)!, sqrt(interval(1, 4)))")
+    .expected_stdout(Exact("4 ± 2 1.5 ± 0.5\n"))
+    .run()
+}
+
+#[test]
+fn interval_mode() -> Result {
+  Test::new()?
+    .argument("--interval")
+    .argument("-p")
+    .argument("53")
+    .program("println(pi, 1 / 3, 2^0.5, sin(pi), sqrt(-4))")
+    .expected_stdout(Exact(
+      "3.141592653589793 ± 2.3e-16 0.3333333333333333 1.414213562373095 ± 1.2e-16 -9.9579925010296e-17 ± 2.3e-16 2i\n",
+    ))
+    .run()
+}
+
 #[test]
 fn iterative_factorial() -> Result {
   Test::new()?
@@ -2151,6 +3100,37 @@ fn less_than_or_equal() -> Result {
     .run()
 }
 
+#[test]
+fn let_declares_local_binding() -> Result {
+  Test::new()?
+    .program(
+      "
+      result = 'global'
+
+      fn collect(n) {
+        let result = []
+
+        for i in 0..n {
+          result = result + [i]
+        }
+
+        return result
+      }
+
+      println(collect(3), result)
+
+      let [a, [b, c]] = [1, [2, 3]]
+      let empty
+      println(a + b + c, empty)
+
+      let a = 'redeclared'
+      println(a)
+      ",
+    )
+    .expected_stdout(Exact("[0, 1, 2] global\n6 null\nredeclared\n"))
+    .run()
+}
+
 #[test]
 fn list_access() -> Result {
   Test::new()?
@@ -2187,9 +3167,7 @@ fn list_access_rejects_non_integer_index() -> Result {
   Test::new()?
     .program("println([1, 2, 3][1.5])")
     .expected_status(1)
-    .expected_stderr(Contains(
-      "List index must be a non-negative finite number",
-    ))
+    .expected_stderr(Contains("Index must be a finite integer"))
     .run()
 }
 
@@ -2300,9 +3278,7 @@ fn logarithms() -> Result {
   Test::new()?
     .program("println(log2(0))")
     .expected_status(1)
-    .expected_stderr(Contains(
-      "Cannot take logarithm of zero or negative number",
-    ))
+    .expected_stderr(Contains("Cannot take logarithm of zero"))
     .run()?;
 
   Test::new()?
@@ -2313,10 +3289,8 @@ fn logarithms() -> Result {
 
   Test::new()?
     .program("println(log10(-5))")
-    .expected_status(1)
-    .expected_stderr(Contains(
-      "Cannot take logarithm of zero or negative number",
-    ))
+    .expected_status(0)
+    .expected_stdout(Exact("0.6989700043360188+1.364376353841841i\n"))
     .run()
 }
 
@@ -2419,12 +3393,427 @@ fn loop_with_continue() -> Result {
 }
 
 #[test]
-fn mixed_type_comparisons() -> Result {
+fn map_access() -> Result {
   Test::new()?
-    .program("println(\"true\" == true)")
+    .program(indoc! {
+      "
+      m = {'a': 1, 2: 'two', true: [3], null: 4}
+      println(m['a'])
+      println(m[2])
+      println(m[true][0])
+      println(m[null])
+      "
+    })
     .expected_status(0)
-    .expected_stdout(Exact("false\n"))
-    .run()?;
+    .expected_stdout(Exact("1\ntwo\n3\n4\n"))
+    .run()
+}
+
+#[test]
+fn map_access_missing_key() -> Result {
+  Test::new()?
+    .program("println({'a': 1}['b'])")
+    .expected_status(1)
+    .expected_stderr(Contains("Key 'b' not found in map"))
+    .run()
+}
+
+#[test]
+fn map_builtins() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      m = {'a': 1, 'b': 2, 'c': 3}
+      println(keys(m))
+      println(values(m))
+      println(has(m, 'b'), has(m, 'd'))
+      println(remove(m, 'b'))
+      println(len(m), len({}))
+      println(bool(m), bool({}))
+      println(list(m))
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "['a', 'b', 'c']\n[1, 2, 3]\ntrue false\n{'a': 1, 'c': 3}\n3 0\ntrue false\n['a', 'b', 'c']\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("keys([1, 2])")
+    .expected_status(1)
+    .expected_stderr(Contains("'[1, 2]' is not a map"))
+    .run()
+}
+
+#[test]
+fn map_element_assignment() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      m = {'a': 1}
+      m['a'] = 10
+      m['b'] = 20
+      println(m)
+
+      grid = {'row': [1, 2], 'nested': {'x': 1}}
+      grid['row'][1] = 5
+      grid['nested']['y'] = 2
+      println(grid)
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "{'a': 10, 'b': 20}\n{'row': [1, 5], 'nested': {'x': 1, 'y': 2}}\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program(indoc! {
+      "
+      m = {}
+      m['a'][0] = 1
+      "
+    })
+    .expected_status(1)
+    .expected_stderr(Contains("Key 'a' not found in map"))
+    .run()
+}
+
+#[test]
+fn map_equality() -> Result {
+  Test::new()?
+    .program("println({'a': 1, 'b': 2} == {'b': 2, 'a': 1})")
+    .expected_status(0)
+    .expected_stdout(Exact("true\n"))
+    .run()?;
+
+  Test::new()?
+    .program("println({'a': 1} == {'a': 2})")
+    .expected_status(0)
+    .expected_stdout(Exact("false\n"))
+    .run()
+}
+
+#[test]
+fn map_iteration() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      m = {'x': 1, 'y': 2}
+
+      for key in m {
+        println(key + ' = ' + m[key])
+      }
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact("x = 1\ny = 2\n"))
+    .run()
+}
+
+#[test]
+fn map_keys_compare_numbers_by_value() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      m = {1: 'one', 0.5: 'half'}
+      println(m[float(1)])
+      println(m[1 / 2])
+      m[2.0] = 'two'
+      println(m)
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact("one\nhalf\n{1: 'one', 0.5: 'half', 2: 'two'}\n"))
+    .run()
+}
+
+#[test]
+fn map_rejects_unhashable_keys() -> Result {
+  Test::new()?
+    .program("m = {[1]: 2}")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot use list as a map key"))
+    .run()?;
+
+  Test::new()?
+    .program("m = {}\nm[{}] = 1")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot use map as a map key"))
+    .run()?;
+
+  Test::new()?
+    .program("m = {1/3: 'a'}\nm[interval(1/3, 1/3)] = 'b'")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot use interval"))
+    .run()?;
+
+  Test::new()?
+    .program("println({interval(5): 'a'}[5])")
+    .expected_stdout(Exact("a\n"))
+    .run()
+}
+
+#[test]
+fn match_binds_in_child_environment() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      x = 'outer'
+      y = match ([1, 2]) { [x, y] => x + y }
+      println(x, y)
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact("outer 3\n"))
+    .run()
+}
+
+#[test]
+fn match_expression() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      fn describe(value) {
+        return match (value) {
+          0 => 'zero',
+          -1 => 'minus one',
+          null => 'nothing',
+          'hi' => 'greeting',
+          [] => 'empty',
+          [only] => f'one {only}',
+          [head, ...tail] => f'head {head} tail {tail}',
+          n: number if n > 100 => 'big',
+          n: number => f'number {n}',
+          _: map => 'map',
+          _ => 'other',
+        }
+      }
+
+      for value in [0, -1, null, 'hi', [], [5], [1, 2, 3], 1000, 7, {}, true] {
+        println(describe(value))
+      }
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "zero\nminus one\nnothing\ngreeting\nempty\none 5\n\
+       head 1 tail [2, 3]\nbig\nnumber 7\nmap\nother\n",
+    ))
+    .run()
+}
+
+#[test]
+fn match_matrix_type() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      fn kind(value) {
+        return match (value) {
+          m: matrix => f'matrix {det(m)}',
+          _ => 'other',
+        }
+      }
+
+      println(kind(matrix([[1, 2], [3, 4]])), kind([[1, 2], [3, 4]]))
+      "
+    })
+    .expected_stdout(Exact("matrix -2 other\n"))
+    .run()
+}
+
+#[test]
+fn match_polynomial_type() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      fn kind(value) {
+        return match (value) {
+          p: polynomial => f'polynomial {p}',
+          _ => 'other',
+        }
+      }
+
+      println(kind(poly(1, 0, -2)), kind([1, 0, -2]))
+      "
+    })
+    .expected_stdout(Exact("polynomial x^2 - 2 other\n"))
+    .run()
+}
+
+#[test]
+fn match_with_unknown_type() -> Result {
+  Test::new()?
+    .program("match (1) { n: integer => n }")
+    .expected_status(1)
+    .expected_stderr(Contains("Unknown type `integer`"))
+    .run()
+}
+
+#[test]
+fn match_without_matching_arm() -> Result {
+  Test::new()?
+    .program("println(match ([1, 2]) { [x] => x, s: string => s })")
+    .expected_status(1)
+    .expected_stderr(Contains("No match arm matches '[1, 2]'"))
+    .run()
+}
+
+#[test]
+fn math_notation_ambiguities() -> Result {
+  Test::new()?
+    .program("println(1 < 2 > 0)")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Ambiguous comparison chain mixing `<` and `>`, split it with `&&`",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println(|1 | 2|)")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Ambiguous `|` inside absolute value bars, wrap it in parentheses",
+    ))
+    .run()
+}
+
+#[test]
+fn matrices() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      m = matrix([[1, 2], [3, 4]])
+      println(m * m)
+      println(m ^ -1)
+      println(det(m), rank(matrix([[1, 2], [2, 4]])), m[1])
+      println(solve(m, vector(5, 6)))
+      println(transpose(vector(1, 2, 3)) * vector(4, 5, 6))
+      println(hadamard(vector(1, 2), vector(3, 4)) == vector(3, 8))
+      println(hadamard(m, m))
+      println(vector(1, 2) .* vector(3, 4) == vector(3, 8), 2 .* 3)
+      println(identity(2) + 1)
+      println(eigenvalues(matrix([[2, 1], [1, 2]])))
+      "
+    })
+    .expected_stdout(Exact(indoc! {
+      "
+      ⎡ 7  10⎤
+      ⎣15  22⎦
+      ⎡ -2     1⎤
+      ⎣1.5  -0.5⎦
+      -2 1 [3, 4]
+      ⎡ -4⎤
+      ⎣4.5⎦
+      [32]
+      true
+      ⎡1   4⎤
+      ⎣9  16⎦
+      true 6
+      ⎡2  1⎤
+      ⎣1  2⎦
+      [1, 3]
+      "
+    }))
+    .run()
+}
+
+#[test]
+fn matrix_errors() -> Result {
+  Test::new()?
+    .program("matrix([[1, 2]]) * matrix([[1], [2], [3]])")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot multiply 1x2 and 3x1 matrices"))
+    .run()?;
+
+  Test::new()?
+    .program("vector(1, 2) * vector(3, 4)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot multiply 2x1 and 2x1 matrices"))
+    .run()?;
+
+  Test::new()?
+    .program("vector(1, 2) .* vector(1, 2, 3)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot multiply 2x1 and 3x1 matrices"))
+    .run()?;
+
+  Test::new()?
+    .program("inverse(matrix([[1, 2], [2, 4]]))")
+    .expected_status(1)
+    .expected_stderr(Contains("Matrix is singular"))
+    .run()?;
+
+  Test::new()?
+    .program("det(matrix([[1, 2]]))")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot take the determinant of a non-square 1x2 matrix",
+    ))
+    .run()
+}
+
+#[test]
+fn member_access() -> Result {
+  Test::new()?
+    .program("m = {'a': {'b': 2}}\nprintln(m.a.b)")
+    .expected_stdout(Exact("2\n"))
+    .run()?;
+
+  Test::new()?
+    .program("m = {'a': 1}\nprintln(m.c)")
+    .expected_status(1)
+    .expected_stderr(Contains("Key 'c' not found in map"))
+    .run()?;
+
+  Test::new()?
+    .program("println([1].length)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot access `length` on list"))
+    .run()
+}
+
+#[test]
+fn membership() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      println(2 in [1, 2, 3], 4 in [1, 2, 3])
+      println('ell' in 'hello', 'a' in {'a': 1}, 'b' in {'a': 1})
+      println(7 in range(1, 20, 3), 8 in range(1, 20, 3), 2.5 in 0..5)
+      println('x' in 0..5, 1 + 1 in [2] == true)
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "true false\ntrue true false\ntrue false false\nfalse true\n",
+    ))
+    .run()
+}
+
+#[test]
+fn membership_errors() -> Result {
+  Test::new()?
+    .program("println(1 in 5)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot test membership in number"))
+    .run()?;
+
+  Test::new()?
+    .program("println(1 in 'abc')")
+    .expected_status(1)
+    .expected_stderr(Contains("'1' is not a string"))
+    .run()
+}
+
+#[test]
+fn mixed_type_comparisons() -> Result {
+  Test::new()?
+    .program("println(\"true\" == true)")
+    .expected_status(0)
+    .expected_stdout(Exact("false\n"))
+    .run()?;
 
   Test::new()?
     .program("println(\"false\" != false)")
@@ -2542,6 +3931,69 @@ fn multiplication() -> Result {
     .run()
 }
 
+#[test]
+fn named_arguments() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn describe(name, greeting = 'Hello', punctuation = '!') {
+        return greeting + ', ' + name + punctuation
+      }
+      println(describe('val', punctuation: '?'))
+      println(describe(punctuation: '.', name: 'you'))
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("Hello, val?\nHello, you.\n"))
+    .run()?;
+
+  Test::new()?
+    .program("fn f(x) {}\nf(y: 1)")
+    .expected_status(1)
+    .expected_stderr(Contains("Function `f` has no parameter named `y`"))
+    .run()?;
+
+  Test::new()?
+    .program("fn f(x, y) {}\nf(1, x: 2)")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Function `f` got multiple values for argument `x`",
+    ))
+    .run()?;
+
+  for program in ["fn f(x) {}\nf(1, x: 2)", "fn f(x) {}\nf(x: 1, x: 2)"] {
+    Test::new()?
+      .program(program)
+      .expected_status(1)
+      .expected_stderr(Contains(
+        "Function `f` got multiple values for argument `x`",
+      ))
+      .run()?;
+  }
+
+  Test::new()?
+    .program("fn f(x, y = 1) {}\nf(y: 2)")
+    .expected_status(1)
+    .expected_stderr(Contains("Function `f` is missing argument `x`"))
+    .run()?;
+
+  Test::new()?
+    .program("sqrt(x: 4)")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Function `sqrt` does not accept named arguments",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("f(a: 1, 2)")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Positional argument cannot follow named arguments",
+    ))
+    .run()
+}
+
 #[test]
 fn natural_logarithm() -> Result {
   Test::new()?
@@ -2573,17 +4025,13 @@ fn natural_logarithm() -> Result {
   Test::new()?
     .program("println(ln(0))")
     .expected_status(1)
-    .expected_stderr(Contains(
-      "Cannot take logarithm of zero or negative number",
-    ))
+    .expected_stderr(Contains("Cannot take logarithm of zero"))
     .run()?;
 
   Test::new()?
     .program("println(ln(-1))")
-    .expected_status(1)
-    .expected_stderr(Contains(
-      "Cannot take logarithm of zero or negative number",
-    ))
+    .expected_status(0)
+    .expected_stdout(Exact("3.141592653589793i\n"))
     .run()
 }
 
@@ -2608,6 +4056,40 @@ fn negate_integer_literal() -> Result {
     .run()
 }
 
+#[test]
+fn negative_index_out_of_bounds() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      a = [1, 2, 3]
+      a[-4] = 0
+      "
+    })
+    .expected_status(1)
+    .expected_stderr(Contains("Index -4 out of bounds for list of length 3"))
+    .run()
+}
+
+#[test]
+fn negative_indexing() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      a = [1, 2, 3]
+      println(a[-1], a[-3])
+
+      a[-1] = 30
+      a[-2] += 10
+      println(a)
+
+      println('héllo'[-4])
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact("3 1\n[1, 12, 30]\né\n"))
+    .run()
+}
+
 #[test]
 fn nested_function_calls() -> Result {
   Test::new()?
@@ -2846,6 +4328,26 @@ fn not_equal_to() -> Result {
     .run()
 }
 
+#[test]
+fn null_coalescing() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn nothing() {}
+      println(nothing() ?? 'default', 0 ?? 1, null ?? null ?? 3)
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("default 0 3\n"))
+    .run()?;
+
+  Test::new()?
+    .program("println(1 ?? undefined)")
+    .expected_status(0)
+    .expected_stdout(Exact("1\n"))
+    .run()
+}
+
 #[test]
 fn null_values() -> Result {
   Test::new()?
@@ -2895,14 +4397,114 @@ fn null_values() -> Result {
     .run()?;
 
   Test::new()?
-    .program(indoc! {
-      "
-      println()
-      println(println())
-      "
-    })
-    .expected_status(0)
-    .expected_stdout(Exact("\n\nnull\n"))
+    .program(indoc! {
+      "
+      println()
+      println(println())
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact("\n\nnull\n"))
+    .run()
+}
+
+#[test]
+fn number_literal_forms() -> Result {
+  Test::new()?
+    .program("println(0xff, 0B1010, 0o17, 1_000_000, 6.022e23, 2.5E-3)")
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "255 10 15 1000000 602200000000000000000000 0.0025\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println(1e-30 * 1e30 == 1)")
+    .expected_status(0)
+    .expected_stdout(Exact("true\n"))
+    .run()?;
+
+  Test::new()?
+    .program(r#"println(int("0x1f"), int("1_000.7"), float("2.5e2"))"#)
+    .expected_status(0)
+    .expected_stdout(Exact("31 1000 250\n"))
+    .run()?;
+
+  Test::new()?
+    .program(r#"int("1__0")"#)
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot convert '1__0' to int"))
+    .run()?;
+
+  Test::new()?
+    .program("x = 1e99999999999")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Exponent too large in number literal `1e99999999999`",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("x = 1e400000000")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Exponent too large in number literal `1e400000000`",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program(r#"int("1e400000000")"#)
+    .expected_status(1)
+    .expected_stderr(Contains("exponent too large"))
+    .run()
+}
+
+#[test]
+fn number_theory() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      println(is_prime(97), is_prime(561), is_prime(2^89 - 1), next_prime(13))
+      println(factor(360), factor(-12), factor(1))
+      println(divisors(28), totient(36))
+      println(factorial(20), binomial(10, 3), binomial(5, 7))
+      println(fibonacci(100), fibonacci(-10))
+      println(isqrt(99), iroot(1000, 3), iroot(-30, 3))
+      "
+    })
+    .expected_stdout(Exact(indoc! {
+      "
+      true false true 17
+      [[2, 3], [3, 2], [5, 1]] [[-1, 1], [2, 2], [3, 1]] []
+      [1, 2, 4, 7, 14, 28] 12
+      2432902008176640000 120 0
+      354224848179261915075 -55
+      9 10 -3
+      "
+    }))
+    .run()
+}
+
+#[test]
+fn number_theory_errors() -> Result {
+  Test::new()?
+    .program("is_prime(2.5)")
+    .expected_status(1)
+    .expected_stderr(Contains("'2.5' is not an integer"))
+    .run()?;
+
+  Test::new()?
+    .program("factor(0)")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Argument to `factor` must be a non-zero integer",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("iroot(-16, 2)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot take an even root of a negative integer"))
     .run()
 }
 
@@ -2933,6 +4535,59 @@ fn operator_precedence() -> Result {
     .run()
 }
 
+#[test]
+fn polynomial_errors() -> Result {
+  Test::new()?
+    .program("roots(poly(0))")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot find the roots of the zero polynomial"))
+    .run()?;
+
+  Test::new()?
+    .program("gcd(poly(1, 0.5), poly(1, sqrt(2)))")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot take the gcd of polynomials with approximate coefficients",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("2 ^ poly(1, 1)")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot apply '^' to number and polynomial"))
+    .run()
+}
+
+#[test]
+fn polynomials() -> Result {
+  Test::new()?
+    .argument("-p")
+    .argument("53")
+    .program(indoc! {
+      "
+      p = poly(1, -3, 2)
+      q = poly([1, 0, -2])
+      println(p, '|', p * q, '|', p - q, '|', -q)
+      println(poly(1, 0, 0, 1) / poly(1, 1), '|', poly(1, 2, 3) % poly(1, 1))
+      println(gcd(poly(1, 0, -1), poly(1, -2, 1)), '|', q ^ 2)
+      println(polyval(p, 3), derivative(p), '|', antiderivative(poly(3, 2, 1)))
+      println(roots(p), roots(q), roots(poly(1, 0, 1)))
+      println(roots(poly(1, -3, 3, -1)), list(q), eigenvalues(identity(2)))
+      "
+    })
+    .expected_stdout(Exact(indoc! {
+      "
+      x^2 - 3x + 2 | x^4 - 3x^3 + 6x - 4 | -3x + 4 | -x^2 + 2
+      x^2 - x + 1 | 2
+      x - 1 | x^4 - 4x^2 + 4
+      2 2x - 3 | x^3 + x^2 + x
+      [1, 2] [-1.414213562373095, 1.414213562373095] [-i, i]
+      [1, 1, 1] [1, 0, -2] [1, 1]
+      "
+    }))
+    .run()
+}
+
 #[test]
 fn power() -> Result {
   Test::new()?
@@ -2975,6 +4630,61 @@ fn print_without_newline() -> Result {
     .run()
 }
 
+#[test]
+fn range_bounds_must_be_integers() -> Result {
+  Test::new()?
+    .program("println(0..1.5)")
+    .expected_status(1)
+    .expected_stderr(Contains("Range bounds must be finite integers"))
+    .run()
+}
+
+#[test]
+fn range_expressions() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      n = 5
+      println(0..n, 1..=n, range(0, 10, 3))
+      println(list(0..n - 1), list(1..=n), list(n..0))
+      println(0..3 == range(0, 3), 1..1 == 5..2, 0..3 == [0, 1, 2])
+      println(sum(1..=100), bool(0..0))
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "range(0, 5) range(1, 6) range(0, 10, 3)\n\
+       [0, 1, 2, 3] [1, 2, 3, 4, 5] []\n\
+       true true false\n\
+       5050 false\n",
+    ))
+    .run()
+}
+
+#[test]
+fn range_is_lazy() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      r = range(0, 9223372036854775807, 2)
+      println(len(r), r[-1], r[1000000000000])
+      println(9223372036854775806 in r, 9223372036854775805 in r)
+      println(r[2:5], (0..10)[::-3])
+      println(r[len(r)])
+      "
+    })
+    .expected_status(1)
+    .expected_stdout(Exact(
+      "4611686018427387904 9223372036854775806 2000000000000\n\
+       true false\n\
+       [4, 6, 8] [9, 6, 3, 0]\n",
+    ))
+    .expected_stderr(Contains(
+      "Index 4611686018427387904 out of bounds for range of length 4611686018427387904",
+    ))
+    .run()
+}
+
 #[test]
 fn range_rejects_zero_step() -> Result {
   Test::new()?
@@ -2987,11 +4697,45 @@ fn range_rejects_zero_step() -> Result {
 #[test]
 fn range_stops_before_overflow() -> Result {
   Test::new()?
-    .program("println(range(9223372036854775806, 9223372036854775807, 2))")
+    .program(
+      "println(list(range(9223372036854775806, 9223372036854775807, 2)))",
+    )
     .expected_stdout(Exact("[9223372036854775806]\n"))
     .run()
 }
 
+#[test]
+fn raw_strings() -> Result {
+  Test::new()?
+    .program(r#"println(r"C:\new\table", r'\u{41}')"#)
+    .expected_status(0)
+    .expected_stdout(Exact("C:\\new\\table \\u{41}\n"))
+    .run()
+}
+
+#[test]
+fn rest_parameters() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn total(first, ...rest) { return first + sum(rest) }
+      fn collect(...items) { return items }
+      println(total(1), total(1, 2, 3), collect(), collect('a', 'b'))
+      ",
+    )
+    .expected_status(0)
+    .expected_stdout(Exact("1 6 [] ['a', 'b']\n"))
+    .run()?;
+
+  Test::new()?
+    .program("fn f(a, ...rest) {}\nf()")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Function `f` expects at least 1 argument, got 0",
+    ))
+    .run()
+}
+
 #[test]
 fn secant() -> Result {
   Test::new()?
@@ -3099,6 +4843,105 @@ fn simple_loop() -> Result {
     .run()
 }
 
+#[test]
+fn slice_assignment() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      a = [1, 2, 3, 4, 5]
+      a[1:3] = ['x']
+      println(a)
+
+      a[:0] = [0]
+      println(a)
+
+      a[::2] = [true, true, true]
+      println(a)
+
+      a[-2:] += [6]
+      println(a)
+
+      m = {'xs': [1, 2, 3]}
+      m['xs'][::-1] = [7, 8, 9]
+      println(m)
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "[1, 'x', 4, 5]\n\
+       [0, 1, 'x', 4, 5]\n\
+       [true, 1, true, 4, true]\n\
+       [true, 1, true, 4, true, 6]\n\
+       {'xs': [9, 8, 7]}\n",
+    ))
+    .run()
+}
+
+#[test]
+fn slice_errors() -> Result {
+  Test::new()?
+    .program("println([1, 2, 3][::0])")
+    .expected_status(1)
+    .expected_stderr(Contains("Slice step cannot be zero"))
+    .run()?;
+
+  Test::new()?
+    .program("println([1, 2, 3][0.5:])")
+    .expected_status(1)
+    .expected_stderr(Contains("Slice bound must be a finite integer"))
+    .run()?;
+
+  Test::new()?
+    .program("println(42[1:])")
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot slice number"))
+    .run()?;
+
+  Test::new()?
+    .program(indoc! {
+      "
+      a = [1, 2, 3]
+      a[::2] = [1]
+      "
+    })
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot assign list of length 1 to a slice of length 2",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program(indoc! {
+      "
+      a = [1, 2, 3]
+      a[1:] = 5
+      "
+    })
+    .expected_status(1)
+    .expected_stderr(Contains("Cannot assign number to a slice"))
+    .run()
+}
+
+#[test]
+fn slicing() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      a = [1, 2, 3, 4, 5]
+      println(a[1:3], a[:2], a[3:], a[:])
+      println(a[-2:], a[:-2], a[::2], a[::-1], a[4:1:-2])
+      println(a[10:], a[-10:2], a[3:1])
+      "
+    })
+    .expected_status(0)
+    .expected_stdout(Exact(
+      "[2, 3] [1, 2] [4, 5] [1, 2, 3, 4, 5]\n\
+       [4, 5] [1, 2, 3] [1, 3, 5] [5, 4, 3, 2, 1] [5, 3]\n\
+       [] [1, 2] []\n",
+    ))
+    .run()
+}
+
 #[test]
 fn split_and_convert() -> Result {
   Test::new()?
@@ -3160,8 +5003,8 @@ fn square_root() -> Result {
     .argument("-p")
     .argument("53")
     .program("println(sqrt(-1))")
-    .expected_status(1)
-    .expected_stderr(Contains("Cannot take square root of negative number"))
+    .expected_status(0)
+    .expected_stdout(Exact("i\n"))
     .run()
 }
 
@@ -3176,6 +5019,73 @@ fn stack_size_overflow() -> Result {
     .run()
 }
 
+#[test]
+fn strict_mode_requires_declarations() -> Result {
+  Test::new()?
+    .argument("--strict")
+    .program(
+      "
+      let total = 0
+
+      for i in 1..=3 {
+        total += i
+      }
+
+      fn scale(x, factor = 2) { return x * factor }
+
+      total = scale(total)
+      println(total)
+      ",
+    )
+    .expected_stdout(Exact("12\n"))
+    .run()?;
+
+  Test::new()?
+    .argument("--strict")
+    .program("fn f() { count = 1 }\nf()")
+    .expected_status(1)
+    .expected_stderr(Contains(
+      "Cannot assign to undeclared variable `count`, declare it with `let`",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("count = 1\nprintln(count)")
+    .expected_stdout(Exact("1\n"))
+    .run()
+}
+
+#[test]
+fn string_escapes() -> Result {
+  Test::new()?
+    .program(r#"println("a\tb\nc \"d\" \\ \u{3c0}", 'it\'s')"#)
+    .expected_status(0)
+    .expected_stdout(Exact("a\tb\nc \"d\" \\ π it's\n"))
+    .run()?;
+
+  Test::new()?
+    .program(r#"println("\q")"#)
+    .expected_status(1)
+    .expected_stderr(Contains("Invalid escape sequence `\\q`"))
+    .run()
+}
+
+#[test]
+fn string_indexing() -> Result {
+  Test::new()?
+    .program(indoc! {
+      "
+      s = 'naïve'
+      println(s[2], s[-1], s[1:4], s[::-1], len(s[:]))
+      println(s[5])
+      "
+    })
+    .expected_status(1)
+    .expected_stdout(Exact("ï e aïv evïan 5\n"))
+    .expected_stderr(Contains("Index 5 out of bounds for string of length 5"))
+    .run()
+}
+
 #[test]
 fn string_join() -> Result {
   Test::new()?
@@ -3296,6 +5206,83 @@ fn tangent() -> Result {
     .run()
 }
 
+#[test]
+fn throw_and_catch() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn check(x) {
+        if (x < 0) {
+          throw {'code': 1, 'reason': 'negative'}
+        }
+
+        return x
+      }
+
+      try {
+        check(-1)
+        println('unreachable')
+      } catch (e) {
+        println(e.kind, e.value.reason, match (e) { _: error => 'error', _ => '?' })
+      }
+
+      try {
+        try { throw 'inner' } catch (e) { throw e }
+      } catch (e) {
+        println('rethrown', e.message)
+      }
+      ",
+    )
+    .expected_stdout(Exact("thrown negative error\nrethrown inner\n"))
+    .run()
+}
+
+#[test]
+fn try_finally() -> Result {
+  Test::new()?
+    .program(
+      "
+      fn cleanup() {
+        try {
+          return 'body'
+        } finally {
+          println('cleanup')
+        }
+      }
+
+      println(cleanup())
+
+      for i in 0..2 {
+        try {
+          if (i == 0) { continue }
+          println(i)
+        } finally {
+          println('finally', i)
+        }
+      }
+
+      try {
+        throw 'oops'
+      } catch (e) {
+        println('caught', e.message)
+      } finally {
+        println('done')
+      }
+      ",
+    )
+    .expected_stdout(Exact(
+      "cleanup\nbody\nfinally 0\n1\nfinally 1\ncaught oops\ndone\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("try { throw 'escapes' } finally { println('finally') }")
+    .expected_status(1)
+    .expected_stdout(Exact("finally\n"))
+    .expected_stderr(Contains("escapes"))
+    .run()
+}
+
 #[test]
 fn type_conversions_float() -> Result {
   Test::new()?
@@ -3342,6 +5329,15 @@ fn type_conversions_list() -> Result {
     .run()
 }
 
+#[test]
+fn uncaught_throw() -> Result {
+  Test::new()?
+    .program("throw 'something went wrong'")
+    .expected_status(1)
+    .expected_stderr(Contains("error: something went wrong"))
+    .run()
+}
+
 #[test]
 fn undefined_callee_is_checked_before_arguments() -> Result {
   Test::new()?
@@ -3361,6 +5357,28 @@ fn undefined_variable() -> Result {
     .run()
 }
 
+#[test]
+fn unicode_operators() -> Result {
+  Test::new()?
+    .program(
+      "
+      x = 3
+      println(2 × 3, 7 ÷ 2, 5 − 2, −x, 2 ≤ 3, 3 ≥ 4, 1 ≠ 2)
+      println(x², 10⁻³, 2x², -x², √16, √(x + 1), 2π == τ, φ == phi)
+      ",
+    )
+    .expected_stdout(Exact(
+      "6 3.5 3 -3 true false true\n9 0.001 18 -9 4 2 true true\n",
+    ))
+    .run()?;
+
+  Test::new()?
+    .program("println(√-4)")
+    .expected_status(0)
+    .expected_stdout(Exact("2i\n"))
+    .run()
+}
+
 #[test]
 fn while_loops() -> Result {
   Test::new()?